@@ -1,5 +1,14 @@
+//! A type safe 3D projective geometric algebra.
+//!
+//! ```
+//! use pga::{Line, Plane, Point3};
+//!
+//! let line: Line = Point3::new(0.0, 0.0, 0.0) ^ Point3::new(1.0, 0.0, 0.0);
+//! let plane: Plane = line ^ Point3::new(0.0, 1.0, 0.0);
+//! assert_eq!(plane, Plane::FORWARD);
+//! ```
 #![allow(dead_code)]
-mod pgai;
+pub mod pgai;
 mod test;
 
 #[cfg(feature = "visualization")]
@@ -10,44 +19,113 @@ pub mod visualization;
 mod web;
 
 use glam::Vec3;
+pub use pgai::*;
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 pub use web::*;
 
-/// Generate getter and setter methods for fields of a geometric entity
+/// The common types and traits, for a single glob import.
+///
+/// ```
+/// use pga::prelude::*;
+///
+/// let line: Line = Point3::new(0.0, 0.0, 0.0) ^ Point3::new(1.0, 0.0, 0.0);
+/// assert_eq!(line, Line::X_AXIS);
+/// ```
+pub mod prelude {
+    pub use crate::ApproxEq;
+    pub use crate::pgai::{
+        Blade, BulkWeight, Complement, Direction, GeometricEntity, Join, Line, Meet, Motor, Plane,
+        Point3, Point4, Transformable,
+    };
+}
+
+/// Generate getter and setter methods for fields of a geometric entity, and indexing by `Blade`
 #[macro_export]
 macro_rules! impl_geometric_entity_trait {
-    ($type:ty, [$($coord:ident => $value:tt),*]) => {
-        impl $crate::pgai::GeometricEntity for $type {
+    ($type:ident, [$($coord:ident => $value:tt),*]) => {
+        impl<S: $crate::pgai::Scalar> $crate::pgai::GeometricEntity<S> for $type<S> {
             $(
                 #[inline]
-                fn $coord(&self) -> f32 {
+                fn $coord(&self) -> S {
                     $crate::impl_geometric_entity_trait!(@get_value self, $value)
                 }
 
                 $crate::impl_geometric_entity_trait!(@maybe_setter $coord, $value);
             )*
+
+            fn get_mut(&mut self, blade: $crate::pgai::Blade) -> Option<&mut S> {
+                $($crate::impl_geometric_entity_trait!(@index_mut self, blade, $coord, $value);)*
+                None
+            }
+        }
+
+        impl<S: $crate::pgai::Scalar> std::ops::Index<$crate::pgai::Blade> for $type<S> {
+            type Output = S;
+
+            fn index(&self, blade: $crate::pgai::Blade) -> &S {
+                $($crate::impl_geometric_entity_trait!(@index self, blade, $coord, $value);)*
+                S::ZERO_REF
+            }
+        }
+
+        impl<S: $crate::pgai::Scalar> std::ops::IndexMut<$crate::pgai::Blade> for $type<S> {
+            fn index_mut(&mut self, blade: $crate::pgai::Blade) -> &mut S {
+                match $crate::pgai::GeometricEntity::get_mut(self, blade) {
+                    Some(component) => component,
+                    None => panic!("{} does not store a {:?} component", stringify!($type), blade),
+                }
+            }
         }
     };
     (@get_value $self:ident, $field:ident) => { $self.$field };
-    (@get_value $self:ident, $literal:literal) => { $literal };
+    (@get_value $self:ident, $literal:literal) => { S::from_f32($literal) };
 
     (@maybe_setter $coord:ident, $field:ident) => {
         paste::paste! {
             #[inline]
-            fn [<set_ $coord>](&mut self, value: f32) {
+            fn [<set_ $coord>](&mut self, value: S) {
                 self.$field = value;
             }
         }
     };
     (@maybe_setter $coord:ident, $literal:literal) => {};
+
+    (@index $self:ident, $blade:ident, $coord:ident, $field:ident) => {
+        paste::paste! {
+            if $blade == $crate::pgai::Blade::[<$coord:camel>] {
+                return &$self.$field;
+            }
+        }
+    };
+    // Fixed components are not stored, so they are read through a constant
+    (@index $self:ident, $blade:ident, $coord:ident, 1.0) => {
+        paste::paste! {
+            if $blade == $crate::pgai::Blade::[<$coord:camel>] {
+                return S::ONE_REF;
+            }
+        }
+    };
+    (@index $self:ident, $blade:ident, $coord:ident, $literal:literal) => {
+        compile_error!("only components fixed at 1.0 can be indexed");
+    };
+
+    (@index_mut $self:ident, $blade:ident, $coord:ident, $field:ident) => {
+        paste::paste! {
+            if $blade == $crate::pgai::Blade::[<$coord:camel>] {
+                return Some(&mut $self.$field);
+            }
+        }
+    };
+    (@index_mut $self:ident, $blade:ident, $coord:ident, $literal:literal) => {};
 }
 
 // Generate const constructor
 #[macro_export]
 macro_rules! impl_constructor {
-    ($type:ty, [$($field:ident),*]) => {
-        impl $type {
-            pub const fn new($($field: f32),*) -> Self {
+    ($type:ident, [$($field:ident),*]) => {
+        impl<S: $crate::pgai::Scalar> $type<S> {
+            #[allow(clippy::too_many_arguments)]
+            pub const fn new($($field: S),*) -> Self {
                 Self {
                     $($field),*
                 }
@@ -60,13 +138,13 @@ macro_rules! impl_constructor {
 #[macro_export]
 macro_rules! impl_geometric_entity {
     // For types with only field values (no literals)
-    ($type:ty, [$($coord:ident => $field:ident),*]) => {
+    ($type:ident, [$($coord:ident => $field:ident),*]) => {
         $crate::impl_geometric_entity_trait!($type, [$($coord => $field),*]);
         $crate::impl_constructor!($type, [$($field),*]);
     };
 
     // For types with mixed field values and literals
-    ($type:ty, [$($coord:ident => $value:tt),*], fields: [$($field:ident),*]) => {
+    ($type:ident, [$($coord:ident => $value:tt),*], fields: [$($field:ident),*]) => {
         $crate::impl_geometric_entity_trait!($type, [$($coord => $value),*]);
         $crate::impl_constructor!($type, [$($field),*]);
     };
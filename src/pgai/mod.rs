@@ -1,112 +1,171 @@
 mod types;
 mod wedge;
 
-use std::ops::Neg;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
 pub use types::*;
 
 // ================================================================================================
 // TRAITS
 // ================================================================================================
-pub trait GeometricEntity: Default + Neg {
+/// The floating point types entities can be built from. Everything defaults to `f32`; `f64`
+/// is available for computations that need the extra precision.
+pub trait Scalar:
+    'static
+    + Copy
+    + Default
+    + PartialOrd
+    + fmt::Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const EPSILON: Self;
+    /// `ZERO` and `ONE` behind a reference, for indexing the components an entity fixes.
+    const ZERO_REF: &'static Self;
+    const ONE_REF: &'static Self;
+
+    fn from_f32(value: f32) -> Self;
+    fn sqrt(self) -> Self;
+    fn abs(self) -> Self;
+}
+
+pub trait GeometricEntity<S: Scalar = f32>: Default + Neg {
     #[inline]
-    fn e0(&self) -> f32 {
-        0.0
+    fn e0(&self) -> S {
+        S::ZERO
     }
     #[inline]
-    fn e1(&self) -> f32 {
-        0.0
+    fn e1(&self) -> S {
+        S::ZERO
     }
     #[inline]
-    fn e2(&self) -> f32 {
-        0.0
+    fn e2(&self) -> S {
+        S::ZERO
     }
     #[inline]
-    fn e3(&self) -> f32 {
-        0.0
+    fn e3(&self) -> S {
+        S::ZERO
     }
     #[inline]
-    fn e41(&self) -> f32 {
-        0.0
+    fn e41(&self) -> S {
+        S::ZERO
     }
     #[inline]
-    fn e42(&self) -> f32 {
-        0.0
+    fn e42(&self) -> S {
+        S::ZERO
     }
     #[inline]
-    fn e43(&self) -> f32 {
-        0.0
+    fn e43(&self) -> S {
+        S::ZERO
     }
     #[inline]
-    fn e23(&self) -> f32 {
-        0.0
+    fn e23(&self) -> S {
+        S::ZERO
     }
     #[inline]
-    fn e31(&self) -> f32 {
-        0.0
+    fn e31(&self) -> S {
+        S::ZERO
     }
     #[inline]
-    fn e12(&self) -> f32 {
-        0.0
+    fn e12(&self) -> S {
+        S::ZERO
     }
     #[inline]
-    fn e423(&self) -> f32 {
-        0.0
+    fn e423(&self) -> S {
+        S::ZERO
     }
     #[inline]
-    fn e431(&self) -> f32 {
-        0.0
+    fn e431(&self) -> S {
+        S::ZERO
     }
     #[inline]
-    fn e412(&self) -> f32 {
-        0.0
+    fn e412(&self) -> S {
+        S::ZERO
     }
     #[inline]
-    fn e321(&self) -> f32 {
-        0.0
+    fn e321(&self) -> S {
+        S::ZERO
     }
     #[inline]
-    fn scalar(&self) -> f32 {
-        0.0
+    fn scalar(&self) -> S {
+        S::ZERO
     }
     #[inline]
-    fn antiscalar(&self) -> f32 {
-        0.0
+    fn antiscalar(&self) -> S {
+        S::ZERO
     }
 
     #[inline]
-    fn set_e0(&mut self, _value: f32) {}
+    fn set_e0(&mut self, _value: S) {}
     #[inline]
-    fn set_e1(&mut self, _value: f32) {}
+    fn set_e1(&mut self, _value: S) {}
     #[inline]
-    fn set_e2(&mut self, _value: f32) {}
+    fn set_e2(&mut self, _value: S) {}
     #[inline]
-    fn set_e3(&mut self, _value: f32) {}
+    fn set_e3(&mut self, _value: S) {}
     #[inline]
-    fn set_e41(&mut self, _value: f32) {}
+    fn set_e41(&mut self, _value: S) {}
     #[inline]
-    fn set_e42(&mut self, _value: f32) {}
+    fn set_e42(&mut self, _value: S) {}
     #[inline]
-    fn set_e43(&mut self, _value: f32) {}
+    fn set_e43(&mut self, _value: S) {}
     #[inline]
-    fn set_e23(&mut self, _value: f32) {}
+    fn set_e23(&mut self, _value: S) {}
     #[inline]
-    fn set_e31(&mut self, _value: f32) {}
+    fn set_e31(&mut self, _value: S) {}
     #[inline]
-    fn set_e12(&mut self, _value: f32) {}
+    fn set_e12(&mut self, _value: S) {}
     #[inline]
-    fn set_e423(&mut self, _value: f32) {}
+    fn set_e423(&mut self, _value: S) {}
     #[inline]
-    fn set_e431(&mut self, _value: f32) {}
+    fn set_e431(&mut self, _value: S) {}
     #[inline]
-    fn set_e412(&mut self, _value: f32) {}
+    fn set_e412(&mut self, _value: S) {}
     #[inline]
-    fn set_e321(&mut self, _value: f32) {}
+    fn set_e321(&mut self, _value: S) {}
     #[inline]
-    fn set_scalar(&mut self, _value: f32) {}
+    fn set_scalar(&mut self, _value: S) {}
     #[inline]
-    fn set_antiscalar(&mut self, _value: f32) {}
+    fn set_antiscalar(&mut self, _value: S) {}
+
+    /// The component along `blade`, read with its getter: zero for the blades the entity does
+    /// not store, and the fixed value for those it fixes, like the e0 of a `Point3`.
+    fn get(&self, blade: Blade) -> S {
+        match blade {
+            Blade::Scalar => self.scalar(),
+            Blade::E1 => self.e1(),
+            Blade::E2 => self.e2(),
+            Blade::E3 => self.e3(),
+            Blade::E0 => self.e0(),
+            Blade::E41 => self.e41(),
+            Blade::E42 => self.e42(),
+            Blade::E43 => self.e43(),
+            Blade::E23 => self.e23(),
+            Blade::E31 => self.e31(),
+            Blade::E12 => self.e12(),
+            Blade::E423 => self.e423(),
+            Blade::E431 => self.e431(),
+            Blade::E412 => self.e412(),
+            Blade::E321 => self.e321(),
+            Blade::Antiscalar => self.antiscalar(),
+        }
+    }
 
-    fn length_squared(&self) -> f32 {
+    /// The component along `blade` for writing, or `None` when the entity does not store it:
+    /// the blades whose setters do nothing.
+    #[inline]
+    fn get_mut(&mut self, _blade: Blade) -> Option<&mut S> {
+        None
+    }
+
+    /// Sum of the squares of all sixteen components, the antiscalar included. This is the norm
+    /// of the coordinates, not of the metric: `Motor::normalize` uses the metric norm of a motor.
+    fn length_squared(&self) -> S {
         self.e0() * self.e0()
             + self.e1() * self.e1()
             + self.e2() * self.e2()
@@ -125,12 +184,147 @@ pub trait GeometricEntity: Default + Neg {
             + self.antiscalar() * self.antiscalar()
     }
 
-    fn norm(&self) -> f32 {
+    fn norm(&self) -> S {
         self.length_squared().sqrt()
     }
 
+    /// The scalar product `⟨A B̃⟩₀` of the geometric product with the reverse of `other`. Only
+    /// the Euclidean components contribute, so for a plane it is the dot product of the normals,
+    /// for a line that of the directions, and it equals `length_squared` when the entity has no
+    /// ideal part. Directions square to zero here; `Direction::dot` is their Euclidean product.
+    fn dot(&self, other: &Self) -> S {
+        // The reverse in the grades of the metric, where planes are the vectors: it negates the
+        // lines and the points, where `reverse` negates the lines and the planes. It is taken on
+        // a multivector because a `Point3` cannot hold a negated weight.
+        let other: Multivector<S> = copy_components(other);
+        let reversed = scale_grades(&other, [S::ONE, -S::ONE, -S::ONE, S::ONE, S::ONE]);
+        let product: Multivector<S> = wedge::geometric_product(self, &reversed);
+        product.scalar()
+    }
+
+    /// True when the norm is at most the square root of the machine epsilon, the default
+    /// threshold of `is_zero_eps`.
     fn is_zero(&self) -> bool {
-        self.length_squared() <= f32::EPSILON
+        self.is_zero_eps(S::EPSILON.sqrt())
+    }
+
+    /// Scales every component so that the entity has unit norm.
+    fn normalize(&self) -> Self {
+        let inv_norm = S::ONE / self.norm();
+        scale_grades(self, [inv_norm; 5])
+    }
+
+    /// Like `is_zero`, but with the threshold on the norm chosen by the caller, for results of
+    /// nearly degenerate meets and joins.
+    fn is_zero_eps(&self, epsilon: S) -> bool {
+        self.norm() <= epsilon
+    }
+
+    /// Normalizes the entity, or returns the zero entity when its norm is at most `epsilon`, so
+    /// a degenerate result stays degenerate instead of turning into NaN.
+    fn normalize_eps(&self, epsilon: S) -> Self {
+        self.try_normalize(epsilon).unwrap_or_default()
+    }
+
+    /// Normalizes the entity, or returns `None` when its norm is at most `epsilon`.
+    fn try_normalize(&self, epsilon: S) -> Option<Self> {
+        if self.is_zero_eps(epsilon) {
+            return None;
+        }
+        Some(self.normalize())
+    }
+
+    /// Compares up to a homogeneous scale: both sides are normalized before their components are
+    /// compared, so a line and a positively scaled copy are equal. A negative scale flips the
+    /// orientation and compares unequal, see `projective_approx_eq_unsigned`.
+    fn projective_approx_eq(&self, other: &Self, epsilon: S) -> bool {
+        if self.is_zero() || other.is_zero() {
+            return self.is_zero() && other.is_zero();
+        }
+        let difference = add_components(
+            &self.normalize(),
+            &scale_grades(&other.normalize(), [-S::ONE; 5]),
+        );
+        difference.blades().all(|(_, value)| value.abs() <= epsilon)
+    }
+
+    /// Like `projective_approx_eq`, but also equal when the scale is negative.
+    fn projective_approx_eq_unsigned(&self, other: &Self, epsilon: S) -> bool {
+        self.projective_approx_eq(other, epsilon)
+            || self.projective_approx_eq(&scale_grades(other, [-S::ONE; 5]), epsilon)
+    }
+
+    /// Reverses the order of the factors of every basis element, which multiplies grade k by
+    /// (-1)^(k(k-1)/2): bivectors and trivectors change sign.
+    fn reverse(&self) -> Self {
+        scale_grades(self, [S::ONE, S::ONE, -S::ONE, -S::ONE, S::ONE])
+    }
+
+    /// Multiplies grade k by (-1)^k, negating the odd grades (points and planes).
+    fn grade_involution(&self) -> Self {
+        scale_grades(self, [S::ONE, -S::ONE, S::ONE, -S::ONE, S::ONE])
+    }
+
+    /// Keeps only the components of grade k: 0 is the scalar, 1 the point components, 2 the line
+    /// components, 3 the plane components and 4 the antiscalar.
+    fn grade(&self, k: usize) -> Self {
+        let mut factors = [S::ZERO; 5];
+        if let Some(factor) = factors.get_mut(k) {
+            *factor = S::ONE;
+        }
+        scale_grades(self, factors)
+    }
+
+    /// The components above epsilon in magnitude, with the name of their basis element, in
+    /// order of grade: `Line::X_AXIS` yields only `("e41", 1.0)`.
+    fn blades(&self) -> impl Iterator<Item = (&'static str, S)> {
+        [
+            ("scalar", self.scalar()),
+            ("e1", self.e1()),
+            ("e2", self.e2()),
+            ("e3", self.e3()),
+            ("e0", self.e0()),
+            ("e41", self.e41()),
+            ("e42", self.e42()),
+            ("e43", self.e43()),
+            ("e23", self.e23()),
+            ("e31", self.e31()),
+            ("e12", self.e12()),
+            ("e423", self.e423()),
+            ("e431", self.e431()),
+            ("e412", self.e412()),
+            ("e321", self.e321()),
+            ("antiscalar", self.antiscalar()),
+        ]
+        .into_iter()
+        .filter(|(_, value)| value.abs() > S::EPSILON)
+    }
+
+    /// Bitmask with bit k set for every grade k that has a non-zero component.
+    fn grades(&self) -> u8 {
+        let grade_norms = [
+            self.scalar() * self.scalar(),
+            self.e1() * self.e1()
+                + self.e2() * self.e2()
+                + self.e3() * self.e3()
+                + self.e0() * self.e0(),
+            self.e41() * self.e41()
+                + self.e42() * self.e42()
+                + self.e43() * self.e43()
+                + self.e23() * self.e23()
+                + self.e31() * self.e31()
+                + self.e12() * self.e12(),
+            self.e423() * self.e423()
+                + self.e431() * self.e431()
+                + self.e412() * self.e412()
+                + self.e321() * self.e321(),
+            self.antiscalar() * self.antiscalar(),
+        ];
+        grade_norms
+            .iter()
+            .enumerate()
+            .filter(|(_, norm)| **norm > S::EPSILON)
+            .fold(0, |mask, (k, _)| mask | 1 << k)
     }
 }
 
@@ -140,10 +334,40 @@ pub trait BulkWeight: GeometricEntity {
     fn bulk(&self) -> Self::Bulk;
     fn weight(&self) -> Self::Weight;
 
-    /// Unitizes the plane (normalizes the normal vector)
+    fn bulk_norm(&self) -> f32 {
+        self.bulk().norm()
+    }
+
+    /// The weight norm, which is 1 for a unitized entity and 0 for an ideal one.
+    fn weight_norm(&self) -> f32 {
+        self.weight().norm()
+    }
+
+    /// The bulk norm divided by the weight norm: the distance from the origin for points, lines
+    /// and planes. Infinite for ideal entities.
+    fn geometric_norm(&self) -> f32 {
+        self.bulk_norm() / self.weight_norm()
+    }
+
+    /// True for entities at infinity, whose weight vanishes.
+    fn is_ideal(&self) -> bool {
+        self.is_ideal_eps(f32::EPSILON)
+    }
+
+    /// Like `is_ideal`, with the threshold on the weight norm chosen by the caller.
+    fn is_ideal_eps(&self, epsilon: f32) -> bool {
+        self.weight_norm() <= epsilon
+    }
+
+    /// Scales the entity so that its weight norm is 1. Ideal entities have no weight to divide
+    /// by and are returned unchanged.
     fn unitize(&self) -> Self {
         let mut result = Self::default();
-        let inv_mag = 1.0 / self.weight().norm();
+        let inv_mag = if self.is_ideal() {
+            1.0
+        } else {
+            1.0 / self.weight().norm()
+        };
         result.set_e0(self.e0() * inv_mag);
         result.set_e1(self.e1() * inv_mag);
         result.set_e2(self.e2() * inv_mag);
@@ -169,14 +393,290 @@ pub trait Dual: GeometricEntity {
     fn dual(&self) -> Self::DualType;
 }
 
+/// Complements with respect to the antiscalar: `x ^ x.right_complement()` and
+/// `x.left_complement() ^ x` are the antiscalar for every basis element. Points and planes
+/// complement into each other, lines into lines. The two differ in sign on the odd grades, so
+/// the right complement applied twice gives `-x` for points and planes and `x` for lines.
+pub trait Complement<S: Scalar = f32>: GeometricEntity<S> {
+    type ComplementType: GeometricEntity<S>;
+
+    fn right_complement(&self) -> Self::ComplementType {
+        right_complement(self)
+    }
+
+    fn left_complement(&self) -> Self::ComplementType {
+        right_complement(&self.grade_involution())
+    }
+}
+
+/// Entities a motor moves with the sandwich product, for generic code over mixed collections.
+/// Directions are ideal points, so only the rotation of the motor changes them.
+pub trait Transformable: GeometricEntity + Sized {
+    fn transform_by(self, motor: &Motor) -> Self {
+        motor.transform(&self)
+    }
+}
+
+/// Joins two entities into the smallest entity containing both, the named form of `^`. The
+/// output type follows from the operands, so generic code can join without knowing the grades.
+///
+/// ```
+/// # use pga::{Join, Line, Plane, Point3};
+/// let line: Line = Point3::new(0.0, 0.0, 0.0).join(Point3::new(1.0, 0.0, 0.0));
+/// let plane: Plane = line.join(Point3::new(0.0, 1.0, 0.0));
+/// ```
+pub trait Join<Rhs = Self> {
+    type Output;
+    fn join(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Meets two entities in their intersection, the named form of `&`.
+///
+/// ```
+/// # use pga::{Line, Meet, Plane, Point4};
+/// let line: Line = Plane::LEFT.meet(Plane::UP);
+/// let point: Point4 = Plane::FORWARD.meet(line);
+/// ```
+pub trait Meet<Rhs = Self> {
+    type Output;
+    fn meet(self, rhs: Rhs) -> Self::Output;
+}
+
 pub trait Grade: GeometricEntity {
     const GRADE: u8;
 }
 
+// ================================================================================================
+// BLADES
+// ================================================================================================
+/// The sixteen basis elements, for indexing an entity by component. Reading any blade gives
+/// what its getter gives. Writing is limited to the components the entity stores: assigning
+/// through the index to any other blade panics, `GeometricEntity::get_mut` is the fallible way.
+///
+/// ```
+/// use pga::{Blade, GeometricEntity, Line, Point3};
+///
+/// let mut line = Line::X_AXIS;
+/// line[Blade::E12] = 2.0;
+/// assert_eq!(line[Blade::E41], 1.0);
+/// assert_eq!(line[Blade::E0], 0.0);
+/// assert_eq!(line.mz, 2.0);
+///
+/// let mut point = Point3::new(1.0, 2.0, 3.0);
+/// assert_eq!(point[Blade::E0], 1.0);
+/// assert_eq!(point.get_mut(Blade::E0), None);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Blade {
+    Scalar,
+    E1,
+    E2,
+    E3,
+    E0,
+    E41,
+    E42,
+    E43,
+    E23,
+    E31,
+    E12,
+    E423,
+    E431,
+    E412,
+    E321,
+    Antiscalar,
+}
+
+impl Blade {
+    /// Every blade, in order of grade as in `GeometricEntity::blades`.
+    pub const ALL: [Blade; 16] = [
+        Blade::Scalar,
+        Blade::E1,
+        Blade::E2,
+        Blade::E3,
+        Blade::E0,
+        Blade::E41,
+        Blade::E42,
+        Blade::E43,
+        Blade::E23,
+        Blade::E31,
+        Blade::E12,
+        Blade::E423,
+        Blade::E431,
+        Blade::E412,
+        Blade::E321,
+        Blade::Antiscalar,
+    ];
+}
+
+// ================================================================================================
+// HELPERS
+// ================================================================================================
+
+/// Scales each grade of `value` by the matching factor, indexed by grade.
+fn scale_grades<S: Scalar, T: GeometricEntity<S>>(value: &T, factors: [S; 5]) -> T {
+    let mut result = T::default();
+    result.set_scalar(value.scalar() * factors[0]);
+    result.set_e1(value.e1() * factors[1]);
+    result.set_e2(value.e2() * factors[1]);
+    result.set_e3(value.e3() * factors[1]);
+    result.set_e0(value.e0() * factors[1]);
+    result.set_e41(value.e41() * factors[2]);
+    result.set_e42(value.e42() * factors[2]);
+    result.set_e43(value.e43() * factors[2]);
+    result.set_e23(value.e23() * factors[2]);
+    result.set_e31(value.e31() * factors[2]);
+    result.set_e12(value.e12() * factors[2]);
+    result.set_e423(value.e423() * factors[3]);
+    result.set_e431(value.e431() * factors[3]);
+    result.set_e412(value.e412() * factors[3]);
+    result.set_e321(value.e321() * factors[3]);
+    result.set_antiscalar(value.antiscalar() * factors[4]);
+    result
+}
+
+/// Every component of `value`, in the order of `blades`.
+fn components<S: Scalar, T: GeometricEntity<S>>(value: &T) -> [S; 16] {
+    [
+        value.scalar(),
+        value.e1(),
+        value.e2(),
+        value.e3(),
+        value.e0(),
+        value.e41(),
+        value.e42(),
+        value.e43(),
+        value.e23(),
+        value.e31(),
+        value.e12(),
+        value.e423(),
+        value.e431(),
+        value.e412(),
+        value.e321(),
+        value.antiscalar(),
+    ]
+}
+
+/// Copies every component of `value` into a `U`, dropping the ones `U` does not store.
+fn copy_components<S: Scalar, T: GeometricEntity<S>, U: GeometricEntity<S>>(value: &T) -> U {
+    let mut result = U::default();
+    result.set_scalar(value.scalar());
+    result.set_e1(value.e1());
+    result.set_e2(value.e2());
+    result.set_e3(value.e3());
+    result.set_e0(value.e0());
+    result.set_e41(value.e41());
+    result.set_e42(value.e42());
+    result.set_e43(value.e43());
+    result.set_e23(value.e23());
+    result.set_e31(value.e31());
+    result.set_e12(value.e12());
+    result.set_e423(value.e423());
+    result.set_e431(value.e431());
+    result.set_e412(value.e412());
+    result.set_e321(value.e321());
+    result.set_antiscalar(value.antiscalar());
+    result
+}
+
+/// The right complement of `value`, written into a `U`: e1 goes to e423, e41 to -e23, e423 to -e1,
+/// and so on, following the existing `Dual` implementations.
+fn right_complement<S: Scalar, T: GeometricEntity<S>, U: GeometricEntity<S>>(value: &T) -> U {
+    let mut result = U::default();
+    result.set_scalar(value.antiscalar());
+    result.set_e1(-value.e423());
+    result.set_e2(-value.e431());
+    result.set_e3(-value.e412());
+    result.set_e0(-value.e321());
+    result.set_e41(-value.e23());
+    result.set_e42(-value.e31());
+    result.set_e43(-value.e12());
+    result.set_e23(-value.e41());
+    result.set_e31(-value.e42());
+    result.set_e12(-value.e43());
+    result.set_e423(value.e1());
+    result.set_e431(value.e2());
+    result.set_e412(value.e3());
+    result.set_e321(value.e0());
+    result.set_antiscalar(value.scalar());
+    result
+}
+
+/// Component-wise sum of two entities of the same type.
+fn add_components<S: Scalar, T: GeometricEntity<S>>(lhs: &T, rhs: &T) -> T {
+    let mut result = T::default();
+    result.set_scalar(lhs.scalar() + rhs.scalar());
+    result.set_e1(lhs.e1() + rhs.e1());
+    result.set_e2(lhs.e2() + rhs.e2());
+    result.set_e3(lhs.e3() + rhs.e3());
+    result.set_e0(lhs.e0() + rhs.e0());
+    result.set_e41(lhs.e41() + rhs.e41());
+    result.set_e42(lhs.e42() + rhs.e42());
+    result.set_e43(lhs.e43() + rhs.e43());
+    result.set_e23(lhs.e23() + rhs.e23());
+    result.set_e31(lhs.e31() + rhs.e31());
+    result.set_e12(lhs.e12() + rhs.e12());
+    result.set_e423(lhs.e423() + rhs.e423());
+    result.set_e431(lhs.e431() + rhs.e431());
+    result.set_e412(lhs.e412() + rhs.e412());
+    result.set_e321(lhs.e321() + rhs.e321());
+    result.set_antiscalar(lhs.antiscalar() + rhs.antiscalar());
+    result
+}
+
+/// Writes `value` as a sum of basis elements, e.g. `1.0 e41 - 2.0 e23`, skipping zero terms.
+fn fmt_blades<S: Scalar, T: GeometricEntity<S>>(value: &T, f: &mut fmt::Formatter) -> fmt::Result {
+    let mut first = true;
+    for (name, coefficient) in value.blades() {
+        if first {
+            write!(f, "{coefficient:?}")?;
+        } else if coefficient < S::ZERO {
+            write!(f, " - {:?}", -coefficient)?;
+        } else {
+            write!(f, " + {coefficient:?}")?;
+        }
+        if name != "scalar" {
+            write!(f, " {name}")?;
+        }
+        first = false;
+    }
+    if first {
+        write!(f, "0")?;
+    }
+    Ok(())
+}
+
 // ================================================================================================
 // TRAIT IMPLEMENTATIONS
 // ================================================================================================
 
+macro_rules! impl_scalar {
+    ($t:ty) => {
+        impl Scalar for $t {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+            const EPSILON: Self = <$t>::EPSILON;
+            const ZERO_REF: &'static Self = &0.0;
+            const ONE_REF: &'static Self = &1.0;
+
+            #[inline]
+            fn from_f32(value: f32) -> Self {
+                value as $t
+            }
+            #[inline]
+            fn sqrt(self) -> Self {
+                <$t>::sqrt(self)
+            }
+            #[inline]
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+        }
+    };
+}
+
+impl_scalar!(f32);
+impl_scalar!(f64);
+
 impl<T> crate::ApproxEq for T
 where
     T: GeometricEntity,
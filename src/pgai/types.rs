@@ -1,95 +1,199 @@
 use crate::{
-    impl_geometric_entity,
-    pgai::{BulkWeight, Dual},
+    impl_geometric_entity, impl_geometric_entity_trait,
+    pgai::{
+        BulkWeight, Complement, Dual, GeometricEntity, Scalar, Transformable, add_components,
+        components, copy_components, fmt_blades, scale_grades, wedge::geometric_product,
+    },
 };
-use glam::{Vec3, Vec4};
+use glam::{Mat3, Mat4, Quat, Vec3, Vec4};
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::ops::{Neg, Not};
 
 // ================================================================================================
 // GEOMETRIC ENTITIES
 // ================================================================================================
 #[derive(Default, Clone, Copy, PartialEq, Debug)]
-pub struct Point4 {
-    pub x: f32,
-    pub y: f32,
-    pub z: f32,
-    pub w: f32,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Point4<S = f32> {
+    pub x: S,
+    pub y: S,
+    pub z: S,
+    pub w: S,
 }
 
 #[derive(Default, Clone, Copy, PartialEq, Debug)]
-pub struct Line {
-    pub vx: f32,
-    pub vy: f32,
-    pub vz: f32,
-    pub mx: f32,
-    pub my: f32,
-    pub mz: f32,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Line<S = f32> {
+    pub vx: S,
+    pub vy: S,
+    pub vz: S,
+    pub mx: S,
+    pub my: S,
+    pub mz: S,
 }
 
 #[derive(Default, Clone, Copy, PartialEq, Debug)]
-pub struct Plane {
-    pub x: f32,
-    pub y: f32,
-    pub z: f32,
-    pub w: f32,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Plane<S = f32> {
+    pub x: S,
+    pub y: S,
+    pub z: S,
+    pub w: S,
 }
 
 #[derive(Default, Clone, Copy, PartialEq, Debug)]
-pub struct Direction {
-    pub x: f32,
-    pub y: f32,
-    pub z: f32,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Direction<S = f32> {
+    pub x: S,
+    pub y: S,
+    pub z: S,
 }
 
 #[derive(Default, Clone, Copy, PartialEq, Debug)]
-pub struct LineDirection {
-    pub x: f32,
-    pub y: f32,
-    pub z: f32,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LineDirection<S = f32> {
+    pub x: S,
+    pub y: S,
+    pub z: S,
 }
 
 #[derive(Default, Clone, Copy, PartialEq, Debug)]
-pub struct LineMoment {
-    pub x: f32,
-    pub y: f32,
-    pub z: f32,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LineMoment<S = f32> {
+    pub x: S,
+    pub y: S,
+    pub z: S,
 }
 
 #[derive(Default, Clone, Copy, PartialEq, Debug)]
-pub struct PlaneDirection {
-    pub x: f32,
-    pub y: f32,
-    pub z: f32,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlaneDirection<S = f32> {
+    pub x: S,
+    pub y: S,
+    pub z: S,
 }
 
 #[derive(Default, Clone, Copy, PartialEq, Debug)]
-pub struct Origin {
-    pub w: f32,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Origin<S = f32> {
+    pub w: S,
 }
 
 #[derive(Default, Clone, Copy, PartialEq, Debug)]
-pub struct Horizon {
-    pub w: f32,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Horizon<S = f32> {
+    pub w: S,
 }
 
 #[derive(Default, Clone, Copy, PartialEq, Debug)]
-pub struct Point3 {
-    pub x: f32,
-    pub y: f32,
-    pub z: f32,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Point3<S = f32> {
+    pub x: S,
+    pub y: S,
+    pub z: S,
 }
 
+/// A rigid motion stored as an even element of the algebra. The rotational part (rx, ry, rz, rw)
+/// lives on the line direction and scalar components, the translational part (ux, uy, uz, uw) on
+/// the line moment and antiscalar components.
+#[derive(Default, Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Motor<S = f32> {
+    pub rx: S,
+    pub ry: S,
+    pub rz: S,
+    pub rw: S,
+    pub ux: S,
+    pub uy: S,
+    pub uz: S,
+    pub uw: S,
+}
+
+/// A rotation around an axis through the origin, the rotational part of a `Motor`. Like the
+/// motor it lives on the line direction (x, y, z) and scalar (w) components, which are the
+/// components of the matching unit quaternion.
+#[derive(Default, Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rotor<S = f32> {
+    pub x: S,
+    pub y: S,
+    pub z: S,
+    pub w: S,
+}
+
+/// A translation, the even element 1 + ½t with the offset t on the line moment components. The
+/// fields hold half the offset, as in the translational part of a `Motor`.
+#[derive(Default, Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Translator<S = f32> {
+    pub x: S,
+    pub y: S,
+    pub z: S,
+}
+
+/// A general element of the algebra holding all 16 components. Products that mix grades, such
+/// as the geometric product, produce a multivector.
+#[derive(Default, Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Multivector<S = f32> {
+    pub scalar: S,
+    pub e1: S,
+    pub e2: S,
+    pub e3: S,
+    pub e0: S,
+    pub e41: S,
+    pub e42: S,
+    pub e43: S,
+    pub e23: S,
+    pub e31: S,
+    pub e12: S,
+    pub e423: S,
+    pub e431: S,
+    pub e412: S,
+    pub e321: S,
+    pub antiscalar: S,
+}
+
+/// Error returned when converting a multivector into a type that cannot hold all of its
+/// non-zero components. Converting to a `Point3` also requires the e0 component to be 1.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct GradeMismatch;
+
+/// Wraps an entity so it can be used as a `HashMap` key or in a `HashSet`. Equality and hashing
+/// compare the bits of every component, not the values: 0.0 and -0.0 differ, a NaN equals an
+/// identical NaN, and nearly equal entities are never merged.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Bits<T>(pub T);
+
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub enum PointOrDirection {
     Point(Point3),
     Direction(Direction),
 }
 
+/// How a line meets a plane, see `Line::intersect_plane`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum LinePlaneIntersection {
+    /// The line crosses the plane at a single point.
+    Point(Point3),
+    /// The line is parallel to the plane and meets it at infinity, in this direction.
+    Parallel(Direction),
+    /// The line lies in the plane.
+    Coincident,
+}
+
 // ================================================================================================
 // IMPLEMENTATIONS USING MACRO
 // ================================================================================================
 
-fn neg<T: crate::pgai::GeometricEntity>(value: T) -> T {
+fn neg<S: Scalar, T: GeometricEntity<S>>(value: T) -> T {
     let mut result = T::default();
     result.set_e0(-value.e0());
     result.set_e1(-value.e1());
@@ -110,10 +214,14 @@ fn neg<T: crate::pgai::GeometricEntity>(value: T) -> T {
     result
 }
 
+fn angle_between(a: Vec3, b: Vec3) -> f32 {
+    a.normalize().dot(b.normalize()).clamp(-1.0, 1.0).acos()
+}
+
 macro_rules! neg_geometric_entity {
-    ($t:ty) => {
-        impl Neg for $t {
-            type Output = $t;
+    ($t:ident) => {
+        impl<S: Scalar> Neg for $t<S> {
+            type Output = $t<S>;
 
             fn neg(self) -> Self::Output {
                 neg(self)
@@ -141,6 +249,30 @@ macro_rules! geometric_entity_dual {
     };
 }
 
+macro_rules! complement_geometric_entity {
+    ($t:ident, $c:ident) => {
+        impl<S: Scalar> Complement<S> for $t<S> {
+            type ComplementType = $c<S>;
+        }
+    };
+}
+
+macro_rules! transformable_geometric_entity {
+    ($($t:ident),*) => {
+        $(impl Transformable for $t {})*
+    };
+}
+
+macro_rules! display_geometric_entity {
+    ($t:ident) => {
+        impl<S: Scalar> fmt::Display for $t<S> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt_blades(self, f)
+            }
+        }
+    };
+}
+
 neg_geometric_entity!(Point3);
 neg_geometric_entity!(Point4);
 neg_geometric_entity!(Line);
@@ -151,6 +283,32 @@ neg_geometric_entity!(LineMoment);
 neg_geometric_entity!(Direction);
 neg_geometric_entity!(Horizon);
 neg_geometric_entity!(Origin);
+neg_geometric_entity!(Motor);
+neg_geometric_entity!(Rotor);
+neg_geometric_entity!(Translator);
+neg_geometric_entity!(Multivector);
+
+display_geometric_entity!(Point3);
+display_geometric_entity!(Point4);
+display_geometric_entity!(Line);
+display_geometric_entity!(Plane);
+display_geometric_entity!(Motor);
+display_geometric_entity!(Rotor);
+display_geometric_entity!(Translator);
+display_geometric_entity!(Multivector);
+
+complement_geometric_entity!(Point3, Plane);
+complement_geometric_entity!(Point4, Plane);
+complement_geometric_entity!(Line, Line);
+complement_geometric_entity!(Plane, Point4);
+
+transformable_geometric_entity!(Point3, Point4, Plane, Direction);
+
+impl Transformable for Line {
+    fn transform_by(self, motor: &Motor) -> Line {
+        self.transform(motor)
+    }
+}
 
 geometric_entity_dual!(Direction, PlaneDirection);
 geometric_entity_dual!(LineDirection, LineMoment);
@@ -218,6 +376,51 @@ impl_geometric_entity!(Origin, [
     e0 => w
 ]);
 
+impl_geometric_entity!(Motor, [
+    e41 => rx,
+    e42 => ry,
+    e43 => rz,
+    scalar => rw,
+    e23 => ux,
+    e31 => uy,
+    e12 => uz,
+    antiscalar => uw
+]);
+
+impl_geometric_entity!(Rotor, [
+    e41 => x,
+    e42 => y,
+    e43 => z,
+    scalar => w
+]);
+
+// The offset is given as a vector, so the constructor is written out in the implementation.
+impl_geometric_entity_trait!(Translator, [
+    scalar => 1.0,
+    e23 => x,
+    e31 => y,
+    e12 => z
+]);
+
+impl_geometric_entity!(Multivector, [
+    scalar => scalar,
+    e1 => e1,
+    e2 => e2,
+    e3 => e3,
+    e0 => e0,
+    e41 => e41,
+    e42 => e42,
+    e43 => e43,
+    e23 => e23,
+    e31 => e31,
+    e12 => e12,
+    e423 => e423,
+    e431 => e431,
+    e412 => e412,
+    e321 => e321,
+    antiscalar => antiscalar
+]);
+
 // ================================================================================================
 // IMPLEMENTATIONS
 // ================================================================================================
@@ -227,13 +430,227 @@ impl Line {
     pub const Y_AXIS: Line = Line::new(0.0, 1.0, 0.0, 0.0, 0.0, 0.0);
     pub const Z_AXIS: Line = Line::new(0.0, 0.0, 1.0, 0.0, 0.0, 0.0);
 
+    /// The attitude of the line: its weight, the ideal part that is left when the line is moved
+    /// through the origin.
     pub fn direction(&self) -> LineDirection {
         self.weight()
     }
 
+    /// The unit direction of the line, or `None` for a line at infinity, which has none.
+    pub fn direction_unit(&self) -> Option<Direction> {
+        self.direction_unit_eps(f32::EPSILON)
+    }
+
+    /// Like `direction_unit`, with the weight norm at or below which the line counts as ideal
+    /// chosen by the caller.
+    pub fn direction_unit_eps(&self, epsilon: f32) -> Option<Direction> {
+        if self.is_ideal_eps(epsilon) {
+            return None;
+        }
+        Some(Direction::from(Vec3::from(self.direction()).normalize()))
+    }
+
+    /// The inverse under the geometric product, `L̃ / (L L̃)`, so that `L * L.inverse()` is 1.
+    /// Only the direction contributes to `L L̃`, so ideal lines have no inverse and give `None`.
+    pub fn inverse(&self) -> Option<Line> {
+        if self.weight().is_zero() {
+            return None;
+        }
+        let norm_squared = self.weight().length_squared();
+        Some(scale_grades(&self.reverse(), [1.0 / norm_squared; 5]))
+    }
+
     pub fn through_origin(x: f32, y: f32, z: f32) -> Self {
         Line::new(x, y, z, 0.0, 0.0, 0.0)
     }
+
+    /// The line through `point` running along `direction`.
+    pub fn from_point_direction(point: Point3, direction: Direction) -> Line {
+        point ^ direction
+    }
+
+    /// The line through two points, running from `a` to `b`. Returns `None` when the points
+    /// coincide.
+    pub fn from_two_points(a: Point3, b: Point3) -> Option<Line> {
+        let line: Line = a ^ b;
+        if line.is_zero() {
+            return None;
+        }
+        Some(line)
+    }
+
+    /// The line with Plücker coordinates `(direction, moment)`, where the moment of a line through
+    /// p is p × direction.
+    pub fn from_plucker(direction: [f32; 3], moment: [f32; 3]) -> Line {
+        let [vx, vy, vz] = direction;
+        let [mx, my, mz] = moment;
+        Line::new(vx, vy, vz, mx, my, mz)
+    }
+
+    /// The Plücker coordinates `(direction, moment)` of the line.
+    pub fn to_plucker(self) -> ([f32; 3], [f32; 3]) {
+        ([self.vx, self.vy, self.vz], [self.mx, self.my, self.mz])
+    }
+
+    /// direction · moment, which vanishes for every line that is the join of two points. Lines
+    /// with a non-zero constraint are screws, such as the logarithm of a general motor.
+    pub fn plucker_constraint(&self) -> f32 {
+        self.vx * self.mx + self.vy * self.my + self.vz * self.mz
+    }
+
+    /// True when the lines lie in a common plane, so they either meet or are parallel. The test is
+    /// the vanishing of v1 · m2 + v2 · m1, the antiscalar of their join, which scales with both
+    /// lines, so `epsilon` is best chosen for unitized lines.
+    pub fn is_coplanar(&self, other: &Line, epsilon: f32) -> bool {
+        let reciprocal = self.vx * other.mx
+            + self.vy * other.my
+            + self.vz * other.mz
+            + other.vx * self.mx
+            + other.vy * self.my
+            + other.vz * self.mz;
+        reciprocal.abs() <= epsilon
+    }
+
+    /// The point where two lines cross. Returns `None` for skew lines, parallel lines and ideal
+    /// lines.
+    pub fn intersection(&self, other: &Line) -> Option<Point3> {
+        if self.is_ideal() || other.is_ideal() {
+            return None;
+        }
+        let (a, b) = (self.unitize(), other.unitize());
+        let normal = Vec3::from(a.direction()).cross(Vec3::from(b.direction()));
+        if normal.length() <= 1e-5 || !a.is_coplanar(&b, 1e-5) {
+            return None;
+        }
+        // The plane through `other` perpendicular to the common plane crosses `self` at the point.
+        let plane: Plane = b ^ Direction::from(normal);
+        Some(Point3::from(a & plane))
+    }
+
+    /// Angle in radians, in [0, π], between the directions of two lines.
+    pub fn angle_to(&self, other: &Line) -> f32 {
+        angle_between(self.direction().into(), other.direction().into())
+    }
+
+    /// Foot of the perpendicular from `point` to the line: the plane through the point orthogonal
+    /// to the line meets the line there. Returns NaN coordinates for an ideal line.
+    pub fn project_point(&self, point: &Point3) -> Point3 {
+        if self.weight().is_zero() {
+            return Point3::new(f32::NAN, f32::NAN, f32::NAN);
+        }
+        let plane = *point ^ !self.direction();
+        Point3::from(plane & *self)
+    }
+
+    /// The line from `point` to its foot on this line, meeting it at a right angle. Zero when the
+    /// point lies on the line, where the perpendicular is not unique.
+    pub fn perpendicular_through(&self, point: &Point3) -> Line {
+        *point ^ self.project_point(point)
+    }
+
+    /// Commutator product `(ab - ba) / 2`. For two lines it is the line along their common
+    /// perpendicular, scaled by the sine of the angle between them, so a line commutes with
+    /// itself to zero.
+    pub fn commutator(&self, other: &Line) -> Line {
+        let ab: Multivector = *self * *other;
+        let ba: Multivector = *other * *self;
+        Line::new(
+            0.5 * (ab.e41() - ba.e41()),
+            0.5 * (ab.e42() - ba.e42()),
+            0.5 * (ab.e43() - ba.e43()),
+            0.5 * (ab.e23() - ba.e23()),
+            0.5 * (ab.e31() - ba.e31()),
+            0.5 * (ab.e12() - ba.e12()),
+        )
+    }
+
+    /// The point on the line nearest the origin, `direction × moment / |direction|²`. Returns NaN
+    /// coordinates for an ideal line.
+    pub fn support(&self) -> Point3 {
+        if self.weight().is_zero() {
+            return Point3::new(f32::NAN, f32::NAN, f32::NAN);
+        }
+        let direction = Vec3::from(self.direction());
+        let moment = Vec3::from(self.bulk());
+        Point3::from(direction.cross(moment) / direction.length_squared())
+    }
+
+    /// Reflects an entity through the line, a half turn around it, with the sandwich product
+    /// L X L̃ of the unitized line.
+    pub fn reflect<T: GeometricEntity>(&self, entity: &T) -> T {
+        let line = self.unitize();
+        let reflected: Multivector = geometric_product(&line, entity);
+        geometric_product(&reflected, &line.reverse())
+    }
+
+    /// Moves the line with `motor`, then removes the part of the moment along the direction that
+    /// rounding adds, so that the result still satisfies the Plücker constraint.
+    pub fn transform(&self, motor: &Motor) -> Line {
+        let moved = motor.transform(self);
+        if moved.weight().is_zero() {
+            return moved;
+        }
+        let direction = Vec3::from(moved.direction());
+        let moment = Vec3::from(moved.bulk());
+        let moment = moment - direction * direction.dot(moment) / direction.length_squared();
+        Line::new(
+            direction.x,
+            direction.y,
+            direction.z,
+            moment.x,
+            moment.y,
+            moment.z,
+        )
+    }
+
+    /// True when `point` lies within `epsilon` of the line.
+    pub fn contains_point(&self, point: &Point3, epsilon: f32) -> bool {
+        point.distance_to_line(self) <= epsilon
+    }
+
+    /// Meets the line with `plane`, as a finite point or a direction when they are parallel. A
+    /// line lying in the plane gives a zero direction, see `intersect_plane` to tell it apart.
+    pub fn meet_plane_classified(&self, plane: &Plane) -> PointOrDirection {
+        PointOrDirection::from(*self & *plane)
+    }
+
+    /// Meets the line with `plane` and classifies the result, instead of leaving the caller to
+    /// inspect the bulk and weight of `self & plane`.
+    pub fn intersect_plane(&self, plane: &Plane) -> LinePlaneIntersection {
+        let point: Point4 = *self & *plane;
+        if point.is_zero() {
+            return LinePlaneIntersection::Coincident;
+        }
+        match PointOrDirection::from(point) {
+            PointOrDirection::Point(point) => LinePlaneIntersection::Point(point),
+            PointOrDirection::Direction(direction) => LinePlaneIntersection::Parallel(direction),
+        }
+    }
+
+    /// Exponential map to a screw motor. The norm of the direction is half the rotation angle
+    /// around the line, and the moment adds a translation, so `(θ/2 · axis).exp()` equals
+    /// `Motor::from_rotation(axis, θ)`. An ideal line gives the pure translation 1 + moment.
+    pub fn exp(&self) -> Motor {
+        let angle = self.weight().norm();
+        if angle < f32::EPSILON {
+            return Motor::new(0.0, 0.0, 0.0, 1.0, self.mx, self.my, self.mz, 0.0);
+        }
+        let (sin, cos) = angle.sin_cos();
+        let s = sin / angle;
+        // The line squares to -(angle - ε pitch)², which turns cos and sin into dual numbers.
+        let pitch = (self.vx * self.mx + self.vy * self.my + self.vz * self.mz) / angle;
+        let t = pitch * (cos - s) / angle;
+        Motor::new(
+            self.vx * s,
+            self.vy * s,
+            self.vz * s,
+            cos,
+            self.mx * s + self.vx * t,
+            self.my * s + self.vy * t,
+            self.mz * s + self.vz * t,
+            pitch * sin,
+        )
+    }
 }
 
 impl Plane {
@@ -241,13 +658,537 @@ impl Plane {
     pub const UP: Plane = Plane::new(0.0, 1.0, 0.0, 0.0);
     pub const FORWARD: Plane = Plane::new(0.0, 0.0, 1.0, 0.0);
 
+    /// The attitude of the plane: its weight, the normal without the offset from the origin.
     pub fn direction(&self) -> PlaneDirection {
         self.weight()
     }
+
+    /// The plane `ax + by + cz + d = 0`, with normal `(a, b, c)` pointing to the side where the
+    /// left-hand side is positive. The coefficients are kept as given; call `unitize` for a unit
+    /// normal.
+    pub fn from_coefficients(a: f32, b: f32, c: f32, d: f32) -> Plane {
+        Plane::new(a, b, c, d)
+    }
+
+    /// The inverse under the geometric product, `p / (p p)`, so that `p * p.inverse()` is 1. A
+    /// unitized plane is its own inverse. Ideal planes square to zero and give `None`.
+    pub fn inverse(&self) -> Option<Plane> {
+        if self.weight().is_zero() {
+            return None;
+        }
+        let norm_squared = self.weight().length_squared();
+        Some(scale_grades(self, [1.0 / norm_squared; 5]))
+    }
+
+    /// The unitized plane through three points, oriented like `a ^ b ^ c`. Returns `None` when
+    /// the points are collinear.
+    pub fn from_points(a: Point3, b: Point3, c: Point3) -> Option<Plane> {
+        let plane: Plane = a ^ b ^ c;
+        if plane.weight().is_zero() {
+            return None;
+        }
+        Some(plane.unitize())
+    }
+
+    /// Angle in radians, in [0, π], between the normals of two planes.
+    pub fn angle_to(&self, other: &Plane) -> f32 {
+        angle_between(self.direction().into(), other.direction().into())
+    }
+
+    /// The unit normal. Zero when the plane has no normal (the horizon or a zero plane).
+    pub fn normal(&self) -> Vec3 {
+        Vec3::from(self.unitize().direction())
+    }
+
+    /// The unit normal, or `None` when the plane has no normal (the horizon or a zero plane).
+    pub fn normal_unit(&self) -> Option<Vec3> {
+        self.normal_unit_eps(f32::EPSILON)
+    }
+
+    /// Like `normal_unit`, with the weight norm at or below which the plane counts as ideal
+    /// chosen by the caller.
+    pub fn normal_unit_eps(&self, epsilon: f32) -> Option<Vec3> {
+        if self.is_ideal_eps(epsilon) {
+            return None;
+        }
+        Some(Vec3::from(self.direction()).normalize())
+    }
+
+    /// Signed distance of the plane from the origin along its unit normal, so that the plane is
+    /// `normal() · p = distance()`. Returns NaN when the plane has no normal.
+    pub fn distance(&self) -> f32 {
+        if self.weight().is_zero() {
+            return f32::NAN;
+        }
+        -self.unitize().w
+    }
+
+    /// Signed distance from the plane to `point`, positive on the side the normal points to.
+    /// Returns NaN when the plane has no normal (the horizon or a zero plane).
+    pub fn distance_to_point(&self, point: &Point3) -> f32 {
+        if self.weight().is_zero() {
+            return f32::NAN;
+        }
+        let plane = self.unitize();
+        plane.x * point.x + plane.y * point.y + plane.z * point.z + plane.w
+    }
+
+    /// The point on the plane nearest the origin, the unit normal scaled by minus the distance
+    /// of the origin. Returns NaN coordinates when the plane has no normal.
+    pub fn support(&self) -> Point3 {
+        if self.weight().is_zero() {
+            return Point3::new(f32::NAN, f32::NAN, f32::NAN);
+        }
+        let plane = self.unitize();
+        Point3::new(-plane.x * plane.w, -plane.y * plane.w, -plane.z * plane.w)
+    }
+
+    /// True when `point` lies within `epsilon` of the plane.
+    pub fn contains_point(&self, point: &Point3, epsilon: f32) -> bool {
+        self.distance_to_point(point).abs() <= epsilon
+    }
+
+    /// Which side of the plane `point` is on: `Greater` in the direction of the normal, `Less`
+    /// behind it and `Equal` within `epsilon` of the plane or when the plane has no normal.
+    pub fn side_of(&self, point: &Point3, epsilon: f32) -> Ordering {
+        let distance = self.distance_to_point(point);
+        if distance > epsilon {
+            Ordering::Greater
+        } else if distance < -epsilon {
+            Ordering::Less
+        } else {
+            Ordering::Equal
+        }
+    }
+
+    /// True when the line lies in the plane: its support point and the point one unit further
+    /// along it are both within `epsilon` of the plane. Ideal lines are never contained.
+    pub fn contains_line(&self, line: &Line, epsilon: f32) -> bool {
+        if line.is_ideal() {
+            return false;
+        }
+        let support = Vec3::from(line.support());
+        let further = support + Vec3::from(line.direction()).normalize();
+        self.contains_point(&Point3::from(support), epsilon)
+            && self.contains_point(&Point3::from(further), epsilon)
+    }
+
+    /// Offset of `point` from the plane along the unit normal, i.e. the signed distance times the
+    /// normal. Returns `Direction::ZERO` when the plane has no normal.
+    pub fn reject_point(&self, point: &Point3) -> Direction {
+        if self.weight().is_zero() {
+            return Direction::ZERO;
+        }
+        let distance = self.distance_to_point(point);
+        let normal = self.unitize();
+        Direction::new(
+            normal.x * distance,
+            normal.y * distance,
+            normal.z * distance,
+        )
+    }
+
+    /// The foot of the perpendicular from `point` to the plane, the point minus its rejection.
+    /// Returns `point` unchanged when the plane has no normal.
+    pub fn foot(&self, point: &Point3) -> Point3 {
+        let offset = Vec3::from(self.reject_point(point));
+        Point3::from(Vec3::from(*point) - offset)
+    }
+
+    /// The plane through `line` perpendicular to this one, which holds the normal of this plane.
+    /// Zero when the line runs along the normal.
+    pub fn perpendicular_through(&self, line: &Line) -> Plane {
+        *line ^ !self.direction()
+    }
+
+    /// The mirror image of `point` across the plane.
+    pub fn mirror_point(&self, point: &Point3) -> Point3 {
+        let offset = Vec3::from(self.reject_point(point));
+        Point3::from(Vec3::from(*point) - 2.0 * offset)
+    }
+
+    /// Mirrors an entity across the plane with the sandwich product p X p. The plane should be
+    /// unitized, otherwise the result is scaled by its squared weight. Reflected planes and lines
+    /// keep their position but may come back with the opposite orientation.
+    pub fn reflect<T: GeometricEntity>(&self, entity: &T) -> T {
+        let reflected: Multivector = geometric_product(self, entity);
+        geometric_product(&reflected, self)
+    }
+}
+
+impl Point3 {
+    pub fn distance(&self, other: &Point3) -> f32 {
+        (Vec3::from(*other) - Vec3::from(*self)).length()
+    }
+
+    /// The point halfway between `self` and `other`.
+    pub fn midpoint(&self, other: &Point3) -> Point3 {
+        Point3::from((Vec3::from(*self) + Vec3::from(*other)) * 0.5)
+    }
+
+    /// The mean of the points, or `None` for an empty slice.
+    pub fn centroid(points: &[Point3]) -> Option<Point3> {
+        if points.is_empty() {
+            return None;
+        }
+        let sum: Vec3 = points.iter().map(|point| Vec3::from(*point)).sum();
+        Some(Point3::from(sum / points.len() as f32))
+    }
+
+    /// The point at `radius` from the origin, `polar` radians down from the +Z axis and
+    /// `azimuth` radians counter-clockwise from the +X axis around +Z.
+    pub fn from_spherical(radius: f32, polar: f32, azimuth: f32) -> Point3 {
+        let (sin_polar, cos_polar) = polar.sin_cos();
+        let (sin_azimuth, cos_azimuth) = azimuth.sin_cos();
+        Point3::new(
+            radius * sin_polar * cos_azimuth,
+            radius * sin_polar * sin_azimuth,
+            radius * cos_polar,
+        )
+    }
+
+    /// The `(radius, polar, azimuth)` of the point as taken by `from_spherical`, with the polar
+    /// angle in [0, π] and the azimuth in (-π, π]. Both angles are 0 at the origin.
+    pub fn to_spherical(&self) -> (f32, f32, f32) {
+        let radius = Vec3::from(*self).length();
+        let polar = if radius > 0.0 {
+            (self.z / radius).clamp(-1.0, 1.0).acos()
+        } else {
+            0.0
+        };
+        (radius, polar, self.y.atan2(self.x))
+    }
+
+    /// The point at `radius` from the Z axis, `azimuth` radians counter-clockwise from the +X
+    /// axis around +Z, and at `height` along Z.
+    pub fn from_cylindrical(radius: f32, azimuth: f32, height: f32) -> Point3 {
+        let (sin, cos) = azimuth.sin_cos();
+        Point3::new(radius * cos, radius * sin, height)
+    }
+
+    /// The `(radius, azimuth, height)` of the point as taken by `from_cylindrical`, with the
+    /// azimuth in (-π, π] and 0 on the Z axis.
+    pub fn to_cylindrical(&self) -> (f32, f32, f32) {
+        (self.x.hypot(self.y), self.y.atan2(self.x), self.z)
+    }
+
+    /// The antiscalar of the join a ^ b ^ c ^ d: six times the signed volume of the tetrahedron,
+    /// positive when `d` is behind the plane a ^ b ^ c and zero when the four points are
+    /// coplanar.
+    pub fn orient3d(a: Point3, b: Point3, c: Point3, d: Point3) -> f32 {
+        let plane: Plane = (a ^ b) ^ c;
+        let join: Multivector = plane ^ d;
+        join.antiscalar()
+    }
+
+    /// The area of the triangle abc, half the weight norm of the plane a ^ b ^ c. Never
+    /// negative, zero for collinear points.
+    pub fn triangle_area(a: Point3, b: Point3, c: Point3) -> f32 {
+        let plane: Plane = (a ^ b) ^ c;
+        0.5 * plane.weight_norm()
+    }
+
+    /// The signed volume of the tetrahedron abcd, `orient3d` over 6: positive when `d` is behind
+    /// the plane a ^ b ^ c.
+    pub fn tetra_volume(a: Point3, b: Point3, c: Point3, d: Point3) -> f32 {
+        Point3::orient3d(a, b, c, d) / 6.0
+    }
+
+    /// Distance to `line`. Joining a unitized line with a point gives a plane whose weight norm is
+    /// the distance between them. Returns NaN for a line without a direction (an ideal line).
+    pub fn distance_to_line(&self, line: &Line) -> f32 {
+        if line.weight().is_zero() {
+            return f32::NAN;
+        }
+        let plane: Plane = line.unitize() ^ *self;
+        plane.weight().norm()
+    }
+
+    /// Signed distance to `plane`, see [`Plane::distance_to_point`].
+    pub fn distance_to_plane(&self, plane: &Plane) -> f32 {
+        plane.distance_to_point(self)
+    }
+}
+
+impl Motor {
+    pub const IDENTITY: Motor = Motor::new(0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0);
+
+    pub fn identity() -> Self {
+        Motor::IDENTITY
+    }
+
+    /// Rotation by `angle` radians around `axis`, counter-clockwise when looking against the
+    /// direction of the axis.
+    pub fn from_rotation(axis: Line, angle: f32) -> Self {
+        let axis = axis.unitize();
+        let (sin, cos) = (angle * 0.5).sin_cos();
+        Motor::new(
+            axis.vx * sin,
+            axis.vy * sin,
+            axis.vz * sin,
+            cos,
+            axis.mx * sin,
+            axis.my * sin,
+            axis.mz * sin,
+            0.0,
+        )
+    }
+
+    pub fn from_translation(offset: Vec3) -> Self {
+        Motor::new(
+            0.0,
+            0.0,
+            0.0,
+            1.0,
+            offset.x * 0.5,
+            offset.y * 0.5,
+            offset.z * 0.5,
+            0.0,
+        )
+    }
+
+    /// The rigid motion placing an object at `from` with its local -Z axis pointing at `target`
+    /// and its local Y axis as close to `up` as possible, like a camera. When `up` is parallel to
+    /// the view direction some perpendicular direction is used instead. When `target` is `from`
+    /// there is nothing to aim at and the motion is only the translation to `from`.
+    pub fn look_at(from: Point3, target: Point3, up: Direction) -> Motor {
+        let translation = Motor::from(Translator::new(Vec3::from(from)));
+        let Some(forward) = (Vec3::from(target) - Vec3::from(from)).try_normalize() else {
+            return translation;
+        };
+        let right = forward
+            .cross(Vec3::from(up))
+            .try_normalize()
+            .unwrap_or_else(|| forward.any_orthonormal_vector());
+        let up = right.cross(forward);
+        let rotation = Quat::from_mat3(&Mat3::from_cols(right, up, -forward));
+        translation.compose(&Motor::from(Rotor::from(rotation)))
+    }
+
+    /// The translation taking `from` onto `to`.
+    pub fn translation_between(from: Point3, to: Point3) -> Self {
+        Motor::from_translation(Vec3::from(to) - Vec3::from(from))
+    }
+
+    /// Returns the motor that first applies `other` and then `self`.
+    pub fn compose(&self, other: &Motor) -> Motor {
+        *self * *other
+    }
+
+    /// Scales the motor so that M M̃ = 1. Motors built from rotations and translations are
+    /// already normalized, but products of many motors slowly drift away from it.
+    pub fn normalize(&self) -> Motor {
+        let norm_squared =
+            self.rx * self.rx + self.ry * self.ry + self.rz * self.rz + self.rw * self.rw;
+        // Half of the antiscalar part of M M̃, which has to vanish for a rigid motion.
+        let drift = self.rw * self.uw - self.rx * self.ux - self.ry * self.uy - self.rz * self.uz;
+        let s = 1.0 / norm_squared.sqrt();
+        let d = -drift * s / norm_squared;
+        Motor::new(
+            self.rx * s,
+            self.ry * s,
+            self.rz * s,
+            self.rw * s,
+            self.ux * s - self.rx * d,
+            self.uy * s - self.ry * d,
+            self.uz * s - self.rz * d,
+            self.uw * s + self.rw * d,
+        )
+    }
+
+    /// The motor that undoes this one: the reverse divided by the squared norm of the rotational
+    /// part. For a unit motor this is just the reverse.
+    pub fn inverse(&self) -> Motor {
+        let norm_squared =
+            self.rx * self.rx + self.ry * self.ry + self.rz * self.rz + self.rw * self.rw;
+        scale_grades(&self.reverse(), [1.0 / norm_squared; 5])
+    }
+
+    /// Interpolates from `self` at `t = 0` to `other` at `t = 1` along a screw motion with
+    /// constant speed.
+    pub fn slerp(&self, other: &Motor, t: f32) -> Motor {
+        let delta = (*other * self.inverse()).log();
+        let step = scale_grades(&delta, [t; 5]);
+        step.exp().compose(self)
+    }
+
+    /// Logarithm of a unit motor, the inverse of [`Line::exp`]. The direction of the resulting
+    /// line has half the rotation angle as its norm, in [0, π/2]. The identity gives a zero line
+    /// and a pure translation gives an ideal line.
+    pub fn log(&self) -> Line {
+        let sin = (self.rx * self.rx + self.ry * self.ry + self.rz * self.rz).sqrt();
+        if sin < f32::EPSILON {
+            return Line::new(0.0, 0.0, 0.0, self.ux, self.uy, self.uz);
+        }
+        let angle = sin.atan2(self.rw);
+        let s = sin / angle;
+        let pitch = self.uw / sin;
+        let t = pitch * (self.rw - s) / angle;
+        let (vx, vy, vz) = (self.rx / s, self.ry / s, self.rz / s);
+        Line::new(
+            vx,
+            vy,
+            vz,
+            (self.ux - vx * t) / s,
+            (self.uy - vy * t) / s,
+            (self.uz - vz * t) / s,
+        )
+    }
+
+    /// Homogeneous transform matrix of a unit motor, acting on column vectors like
+    /// [`Motor::transform`]. The rotational part is the quaternion (rx, ry, rz, rw).
+    pub fn to_mat4(self) -> Mat4 {
+        let rotor = Vec3::new(self.rx, self.ry, self.rz);
+        let moment = Vec3::new(self.ux, self.uy, self.uz);
+        let translation = 2.0 * (self.rw * moment + self.uw * rotor + rotor.cross(moment));
+        let rotation = Quat::from_xyzw(self.rx, self.ry, self.rz, self.rw);
+        Mat4::from_rotation_translation(rotation, translation)
+    }
+
+    /// Applies the motion to an entity with the sandwich product M X M̃, where the reverse M̃ is the
+    /// inverse motion of a unit motor. The motor is used as is,
+    /// so call `normalize` first if it is not a unit motor.
+    pub fn transform<T: GeometricEntity>(&self, entity: &T) -> T {
+        let moved: Multivector = geometric_product(self, entity);
+        geometric_product(&moved, &self.reverse())
+    }
+
+    /// Applies `transform` to every point in place, computing the reverse only once.
+    pub fn transform_points(&self, points: &mut [Point3]) {
+        self.transform_in_place(points);
+    }
+
+    /// Applies `transform` to every plane in place, computing the reverse only once.
+    pub fn transform_planes(&self, planes: &mut [Plane]) {
+        self.transform_in_place(planes);
+    }
+
+    fn transform_in_place<T: GeometricEntity>(&self, entities: &mut [T]) {
+        let reverse = self.reverse();
+        for entity in entities {
+            let moved: Multivector = geometric_product(self, entity);
+            *entity = geometric_product(&moved, &reverse);
+        }
+    }
+}
+
+impl Rotor {
+    pub const IDENTITY: Rotor = Rotor::new(0.0, 0.0, 0.0, 1.0);
+
+    /// Rotation by `angle` radians around the direction of `axis`, counter-clockwise when looking
+    /// against it. The rotation is around the parallel axis through the origin, use
+    /// `Motor::from_rotation` to rotate around the line itself.
+    pub fn from_axis_angle(axis: Line, angle: f32) -> Self {
+        let direction = Vec3::from(axis.direction()).normalize();
+        let (sin, cos) = (angle * 0.5).sin_cos();
+        Rotor::new(direction.x * sin, direction.y * sin, direction.z * sin, cos)
+    }
+
+    /// Scales the rotor to unit norm, undoing the drift of repeated products.
+    pub fn normalize(&self) -> Rotor {
+        let s =
+            1.0 / (self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w).sqrt();
+        Rotor::new(self.x * s, self.y * s, self.z * s, self.w * s)
+    }
+
+    /// Rotates an entity with the sandwich product R X R̃. The rotor should be normalized.
+    pub fn transform<T: GeometricEntity>(&self, entity: &T) -> T {
+        let rotated: Multivector = geometric_product(self, entity);
+        geometric_product(&rotated, &self.reverse())
+    }
+
+    pub fn to_quat(self) -> Quat {
+        Quat::from_xyzw(self.x, self.y, self.z, self.w)
+    }
+}
+
+impl Translator {
+    pub const IDENTITY: Translator = Translator {
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+    };
+
+    /// The translation by `offset`.
+    pub fn new(offset: Vec3) -> Self {
+        Translator {
+            x: offset.x * 0.5,
+            y: offset.y * 0.5,
+            z: offset.z * 0.5,
+        }
+    }
+
+    pub fn offset(&self) -> Vec3 {
+        Vec3::new(self.x, self.y, self.z) * 2.0
+    }
+
+    /// Returns the translation by both offsets. Translations commute, so the order does not
+    /// matter.
+    pub fn compose(&self, other: &Translator) -> Translator {
+        Translator::new(self.offset() + other.offset())
+    }
+
+    /// Translates an entity with the sandwich product T X T̃. Directions are left unchanged.
+    pub fn transform<T: GeometricEntity>(&self, entity: &T) -> T {
+        let moved: Multivector = geometric_product(self, entity);
+        geometric_product(&moved, &self.reverse())
+    }
+}
+
+impl Point4 {
+    /// The homogeneous point with weight 1 at the position of `point`.
+    pub fn from_point3(point: Point3) -> Point4 {
+        Point4::new(point.x, point.y, point.z, 1.0)
+    }
+
+    /// The position of the point, dividing by the weight. Infinite for a point at infinity.
+    pub fn project(&self) -> Point3 {
+        Point3::from(*self)
+    }
+
+    /// The position of the point, or `None` for a point at infinity, where `project` would
+    /// divide by a zero weight. `Point3` already converts `From<Point4>`, so this takes the place
+    /// of a `TryFrom`.
+    pub fn try_project(&self) -> Option<Point3> {
+        if self.is_ideal() {
+            return None;
+        }
+        Some(self.project())
+    }
+
+    /// Scales the point in place so that its weight is 1. Points at infinity are left unchanged.
+    pub fn homogenize(&mut self) {
+        *self = self.unitize();
+    }
+
+    /// A mass point: the position scaled by `mass`, with `mass` as the weight.
+    pub fn weighted(point: Point3, mass: f32) -> Point4 {
+        Point4::new(point.x * mass, point.y * mass, point.z * mass, mass)
+    }
+
+    /// The component-wise sum of the points. For mass points this is the total mass at the
+    /// center of mass, which `project` recovers.
+    pub fn sum(points: &[Point4]) -> Point4 {
+        points
+            .iter()
+            .fold(Point4::default(), |sum, point| add_components(&sum, point))
+    }
 }
 
 impl Direction {
     const ZERO: Direction = Direction::new(0.0, 0.0, 0.0);
+
+    /// The cross product. Two directions join in the line at infinity `self ^ other`, whose
+    /// moment is the cross product.
+    pub fn cross(&self, other: &Direction) -> Direction {
+        let moment: LineMoment = *self ^ *other;
+        Direction::new(moment.x, moment.y, moment.z)
+    }
+
+    /// The dot product, the Euclidean inner product of the two directions.
+    pub fn dot(&self, other: &Direction) -> f32 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
 }
 
 impl Origin {
@@ -280,6 +1221,15 @@ impl BulkWeight for Point4 {
     fn weight(&self) -> Origin {
         Origin { w: self.w }
     }
+
+    /// Divides by w, so that the point sits at w = 1 even when w is negative. Ideal points
+    /// (w ≈ 0) are returned unchanged.
+    fn unitize(&self) -> Self {
+        if self.is_ideal() {
+            return *self;
+        }
+        Point4::new(self.x / self.w, self.y / self.w, self.z / self.w, 1.0)
+    }
 }
 
 impl BulkWeight for Plane {
@@ -378,10 +1328,230 @@ impl Dual for Horizon {
     }
 }
 
+// The `#[repr(C)]` entities hold a single scalar type, so they have no padding and are plain
+// old data whenever the scalar is.
+#[cfg(feature = "bytemuck")]
+macro_rules! impl_pod {
+    ($($t:ident),*) => {
+        $(
+            unsafe impl<S: bytemuck::Zeroable> bytemuck::Zeroable for $t<S> {}
+            unsafe impl<S: bytemuck::Pod> bytemuck::Pod for $t<S> {}
+        )*
+    };
+}
+
+#[cfg(feature = "bytemuck")]
+impl_pod!(Point3, Point4, Line, Plane, Direction);
+
+// Like `ApproxEq`, the `approx` comparisons look at every component, so entities are equal only
+// when they share their scale.
+#[cfg(feature = "approx")]
+macro_rules! impl_approx {
+    ($($t:ident),*) => {
+        $(
+            impl approx::AbsDiffEq for $t {
+                type Epsilon = f32;
+
+                fn default_epsilon() -> f32 {
+                    <f32 as approx::AbsDiffEq>::default_epsilon()
+                }
+
+                fn abs_diff_eq(&self, other: &Self, epsilon: f32) -> bool {
+                    crate::ApproxEq::approx_eq_eps(self, other, epsilon)
+                }
+            }
+
+            impl approx::RelativeEq for $t {
+                fn default_max_relative() -> f32 {
+                    <f32 as approx::RelativeEq>::default_max_relative()
+                }
+
+                fn relative_eq(&self, other: &Self, epsilon: f32, max_relative: f32) -> bool {
+                    components(self)
+                        .iter()
+                        .zip(components(other).iter())
+                        .all(|(a, b)| approx::RelativeEq::relative_eq(a, b, epsilon, max_relative))
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "approx")]
+impl_approx!(
+    Point3,
+    Point4,
+    Line,
+    Plane,
+    Direction,
+    LineDirection,
+    LineMoment,
+    PlaneDirection,
+    Origin,
+    Horizon,
+    Motor,
+    Rotor,
+    Translator,
+    Multivector
+);
+
+impl<T: GeometricEntity> Bits<T> {
+    fn components(&self) -> [u32; 16] {
+        components(&self.0).map(f32::to_bits)
+    }
+}
+
+impl<T: GeometricEntity> PartialEq for Bits<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.components() == other.components()
+    }
+}
+
+impl<T: GeometricEntity> Eq for Bits<T> {}
+
+impl<T: GeometricEntity> Hash for Bits<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.components().hash(state);
+    }
+}
+
+impl fmt::Display for GradeMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "multivector has components outside the target type")
+    }
+}
+
+impl std::error::Error for GradeMismatch {}
+
 // ================================================================================================
 // CONVERSIONS
 // ================================================================================================
 
+macro_rules! multivector_conversions {
+    ($($t:ident),*) => {
+        $(
+            impl<S: Scalar> From<$t<S>> for Multivector<S> {
+                fn from(value: $t<S>) -> Self {
+                    copy_components(&value)
+                }
+            }
+        )*
+    };
+    ($($t:ident),* ; fallible) => {
+        $(
+            impl<S: Scalar> TryFrom<Multivector<S>> for $t<S> {
+                type Error = GradeMismatch;
+
+                /// Fails when the multivector has components, beyond rounding errors, that the
+                /// target type does not store.
+                fn try_from(value: Multivector<S>) -> Result<Self, Self::Error> {
+                    let result: $t<S> = copy_components(&value);
+                    let dropped: Multivector<S> = copy_components(&result);
+                    let residual = Multivector::<S>::new(
+                        value.scalar - dropped.scalar,
+                        value.e1 - dropped.e1,
+                        value.e2 - dropped.e2,
+                        value.e3 - dropped.e3,
+                        value.e0 - dropped.e0,
+                        value.e41 - dropped.e41,
+                        value.e42 - dropped.e42,
+                        value.e43 - dropped.e43,
+                        value.e23 - dropped.e23,
+                        value.e31 - dropped.e31,
+                        value.e12 - dropped.e12,
+                        value.e423 - dropped.e423,
+                        value.e431 - dropped.e431,
+                        value.e412 - dropped.e412,
+                        value.e321 - dropped.e321,
+                        value.antiscalar - dropped.antiscalar,
+                    );
+                    if residual.is_zero() {
+                        Ok(result)
+                    } else {
+                        Err(GradeMismatch)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+multivector_conversions!(
+    Point3,
+    Point4,
+    Line,
+    Plane,
+    Direction,
+    LineDirection,
+    LineMoment,
+    PlaneDirection,
+    Origin,
+    Horizon,
+    Motor,
+    Rotor,
+    Translator
+);
+multivector_conversions!(Point3, Line, Plane, Motor; fallible);
+
+// Arrays hold the fields in declaration order, which for lines is the direction followed by the
+// moment, as in `from_plucker`.
+macro_rules! array_conversions {
+    ($($t:ident, $n:literal, [$($field:ident),*]);* $(;)?) => {
+        $(
+            impl<S> From<[S; $n]> for $t<S> {
+                fn from([$($field),*]: [S; $n]) -> Self {
+                    $t { $($field),* }
+                }
+            }
+
+            impl<S> From<$t<S>> for [S; $n] {
+                fn from(value: $t<S>) -> Self {
+                    [$(value.$field),*]
+                }
+            }
+
+            impl<S: Copy> $t<S> {
+                /// The components in the order the entity is built from an array.
+                pub fn to_array(&self) -> [S; $n] {
+                    [$(self.$field),*]
+                }
+            }
+        )*
+    };
+}
+
+array_conversions!(
+    Point3, 3, [x, y, z];
+    Direction, 3, [x, y, z];
+    Point4, 4, [x, y, z, w];
+    Plane, 4, [x, y, z, w];
+    Line, 6, [vx, vy, vz, mx, my, mz];
+);
+
+impl From<Quat> for Rotor {
+    fn from(q: Quat) -> Self {
+        Rotor::new(q.x, q.y, q.z, q.w)
+    }
+}
+
+impl From<Rotor> for Quat {
+    fn from(r: Rotor) -> Self {
+        r.to_quat()
+    }
+}
+
+impl From<Rotor> for Motor {
+    fn from(r: Rotor) -> Self {
+        Motor::new(r.x, r.y, r.z, r.w, 0.0, 0.0, 0.0, 0.0)
+    }
+}
+
+impl From<Translator> for Motor {
+    fn from(t: Translator) -> Self {
+        Motor::new(0.0, 0.0, 0.0, 1.0, t.x, t.y, t.z, 0.0)
+    }
+}
+
 impl From<Point4> for PointOrDirection {
     fn from(p: Point4) -> Self {
         if p.w.abs() < f32::EPSILON {
@@ -432,6 +1602,37 @@ impl From<Vec3> for LineMoment {
     }
 }
 
+impl From<Vec3> for PlaneDirection {
+    fn from(v: Vec3) -> Self {
+        PlaneDirection {
+            x: v.x,
+            y: v.y,
+            z: v.z,
+        }
+    }
+}
+
+impl From<Vec3> for Point3 {
+    fn from(v: Vec3) -> Self {
+        Point3 {
+            x: v.x,
+            y: v.y,
+            z: v.z,
+        }
+    }
+}
+
+impl From<Vec4> for Plane {
+    fn from(v: Vec4) -> Self {
+        Plane {
+            x: v.x,
+            y: v.y,
+            z: v.z,
+            w: v.w,
+        }
+    }
+}
+
 impl From<LineDirection> for Line {
     fn from(ld: LineDirection) -> Self {
         Line {
@@ -1,13 +1,17 @@
-use crate::pgai::{Direction, GeometricEntity, LineMoment};
+use crate::pgai::{
+    Direction, GeometricEntity, Join, LineMoment, Meet, Motor, Multivector, PlaneDirection, Scalar,
+    add_components, copy_components,
+};
 
 use crate::pgai::types::{Line, Plane, Point3, Point4};
-use std::ops::{BitAnd, BitXor};
+use std::ops::{BitAnd, BitOr, BitXor, Mul};
 
-fn wedge<L, R, O>(lhs: L, rhs: R) -> O
+fn wedge<S, L, R, O>(lhs: L, rhs: R) -> O
 where
-    L: GeometricEntity,
-    R: GeometricEntity,
-    O: GeometricEntity,
+    S: Scalar,
+    L: GeometricEntity<S>,
+    R: GeometricEntity<S>,
+    O: GeometricEntity<S>,
 {
     let mut result = O::default();
 
@@ -46,14 +50,22 @@ where
     result.set_e412(lp_e412 - pl_e412);
     result.set_e321(lp_e321 - pl_e321);
 
+    // Point ^ Plane -> antiscalar, and Plane ^ Point with the opposite sign
+    let (lgx, lgy, lgz, lgw) = (lhs.e423(), lhs.e431(), lhs.e412(), lhs.e321());
+    let (rgx, rgy, rgz, rgw) = (rhs.e423(), rhs.e431(), rhs.e412(), rhs.e321());
+    result.set_antiscalar(
+        px * rgx + py * rgy + pz * rgz + pw * rgw - (lgx * qx + lgy * qy + lgz * qz + lgw * qw),
+    );
+
     result
 }
 
-fn antiwedge<L, R, O>(lhs: L, rhs: R) -> O
+fn antiwedge<S, L, R, O>(lhs: L, rhs: R) -> O
 where
-    L: GeometricEntity,
-    R: GeometricEntity,
-    O: GeometricEntity,
+    S: Scalar,
+    L: GeometricEntity<S>,
+    R: GeometricEntity<S>,
+    O: GeometricEntity<S>,
 {
     let mut result = O::default();
     // Plane & Plane -> Line
@@ -111,27 +123,293 @@ where
     result
 }
 
-// This implement the wedge product (^) between different geometric entities
+// The geometric product of the full algebra. Planes are treated as the vectors of the metric
+// (so that e423 * e423 = 1) while e4 is degenerate, which makes this the product used by
+// reflections and motors. Coefficients are named after the basis elements they belong to:
+// 0 is the scalar, 4 is the weight of a point (e0) and 1234 is the antiscalar.
+pub(crate) fn geometric_product<S, L, R, O>(lhs: &L, rhs: &R) -> O
+where
+    S: Scalar,
+    L: GeometricEntity<S>,
+    R: GeometricEntity<S>,
+    O: GeometricEntity<S>,
+{
+    let mut result = O::default();
+
+    let (a0, a1234) = (lhs.scalar(), lhs.antiscalar());
+    let (a1, a2, a3, a4) = (lhs.e1(), lhs.e2(), lhs.e3(), lhs.e0());
+    let (a41, a42, a43) = (lhs.e41(), lhs.e42(), lhs.e43());
+    let (a23, a31, a12) = (lhs.e23(), lhs.e31(), lhs.e12());
+    let (a423, a431, a412, a321) = (lhs.e423(), lhs.e431(), lhs.e412(), lhs.e321());
+
+    let (b0, b1234) = (rhs.scalar(), rhs.antiscalar());
+    let (b1, b2, b3, b4) = (rhs.e1(), rhs.e2(), rhs.e3(), rhs.e0());
+    let (b41, b42, b43) = (rhs.e41(), rhs.e42(), rhs.e43());
+    let (b23, b31, b12) = (rhs.e23(), rhs.e31(), rhs.e12());
+    let (b423, b431, b412, b321) = (rhs.e423(), rhs.e431(), rhs.e412(), rhs.e321());
+
+    result.set_scalar(
+        a0 * b0 - a4 * b4 - a41 * b41 - a42 * b42 - a43 * b43
+            + a423 * b423
+            + a431 * b431
+            + a412 * b412,
+    );
+    result.set_e1(
+        a0 * b1 + a1 * b0 + a2 * b43 - a3 * b42 - a4 * b23 + a41 * b321 + a42 * b3 - a43 * b2
+            + a23 * b4
+            + a31 * b412
+            - a12 * b431
+            + a423 * b1234
+            - a431 * b12
+            + a412 * b31
+            + a321 * b41
+            - a1234 * b423,
+    );
+    result.set_e2(
+        a0 * b2 - a1 * b43 + a2 * b0 + a3 * b41 - a4 * b31 - a41 * b3 + a42 * b321 + a43 * b1
+            - a23 * b412
+            + a31 * b4
+            + a12 * b423
+            + a423 * b12
+            + a431 * b1234
+            - a412 * b23
+            + a321 * b42
+            - a1234 * b431,
+    );
+    result.set_e3(
+        a0 * b3 + a1 * b42 - a2 * b41 + a3 * b0 - a4 * b12 + a41 * b2 - a42 * b1
+            + a43 * b321
+            + a23 * b431
+            - a31 * b423
+            + a12 * b4
+            - a423 * b31
+            + a431 * b23
+            + a412 * b1234
+            + a321 * b43
+            - a1234 * b412,
+    );
+    result.set_e0(
+        a0 * b4 + a4 * b0
+            - a41 * b423
+            - a42 * b431
+            - a43 * b412
+            - a423 * b41
+            - a431 * b42
+            - a412 * b43,
+    );
+    result.set_e41(
+        a0 * b41 - a4 * b423 + a41 * b0 + a42 * b43 - a43 * b42 - a423 * b4 - a431 * b412
+            + a412 * b431,
+    );
+    result.set_e42(
+        a0 * b42 - a4 * b431 - a41 * b43 + a42 * b0 + a43 * b41 + a423 * b412
+            - a431 * b4
+            - a412 * b423,
+    );
+    result.set_e43(
+        a0 * b43 - a4 * b412 + a41 * b42 - a42 * b41 + a43 * b0 - a423 * b431 + a431 * b423
+            - a412 * b4,
+    );
+    result.set_e23(
+        a0 * b23 - a1 * b4 - a2 * b412 + a3 * b431 + a4 * b1 - a41 * b1234 + a42 * b12 - a43 * b31
+            + a23 * b0
+            + a31 * b43
+            - a12 * b42
+            + a423 * b321
+            + a431 * b3
+            - a412 * b2
+            - a321 * b423
+            - a1234 * b41,
+    );
+    result.set_e31(
+        a0 * b31 + a1 * b412 - a2 * b4 - a3 * b423 + a4 * b2 - a41 * b12 - a42 * b1234 + a43 * b23
+            - a23 * b43
+            + a31 * b0
+            + a12 * b41
+            - a423 * b3
+            + a431 * b321
+            + a412 * b1
+            - a321 * b431
+            - a1234 * b42,
+    );
+    result.set_e12(
+        a0 * b12 - a1 * b431 + a2 * b423 - a3 * b4 + a4 * b3 + a41 * b31 - a42 * b23 - a43 * b1234
+            + a23 * b42
+            - a31 * b41
+            + a12 * b0
+            + a423 * b2
+            - a431 * b1
+            + a412 * b321
+            - a321 * b412
+            - a1234 * b43,
+    );
+    result.set_e423(
+        a0 * b423 + a4 * b41 + a41 * b4 + a42 * b412 - a43 * b431 + a423 * b0 + a431 * b43
+            - a412 * b42,
+    );
+    result.set_e431(
+        a0 * b431 + a4 * b42 - a41 * b412 + a42 * b4 + a43 * b423 - a423 * b43
+            + a431 * b0
+            + a412 * b41,
+    );
+    result.set_e412(
+        a0 * b412 + a4 * b43 + a41 * b431 - a42 * b423 + a43 * b4 + a423 * b42 - a431 * b41
+            + a412 * b0,
+    );
+    result.set_e321(
+        a0 * b321 - a1 * b41 - a2 * b42 - a3 * b43 + a4 * b1234
+            - a41 * b1
+            - a42 * b2
+            - a43 * b3
+            - a23 * b423
+            - a31 * b431
+            - a12 * b412
+            + a423 * b23
+            + a431 * b31
+            + a412 * b12
+            + a321 * b0
+            - a1234 * b4,
+    );
+    result.set_antiscalar(
+        a0 * b1234 - a1 * b423 - a2 * b431 - a3 * b412 - a4 * b321
+            + a41 * b23
+            + a42 * b31
+            + a43 * b12
+            + a23 * b41
+            + a31 * b42
+            + a12 * b43
+            + a423 * b1
+            + a431 * b2
+            + a412 * b3
+            + a321 * b4
+            + a1234 * b0,
+    );
+
+    result
+}
+
+// The grade each stored grade has in the metric algebra of the geometric product, where planes
+// are the vectors and points the trivectors. The map is its own inverse.
+const METRIC_GRADE: [usize; 5] = [0, 3, 2, 1, 4];
+
+// The metric inner product: the grade |r - s| part of the geometric product of every grade r part
+// of `lhs` with every grade s part of `rhs`, with grades counted in the metric algebra. Two planes
+// or two lines give a scalar measuring the angle between them, a plane and a point give the line
+// through the point perpendicular to the plane. This is the `|` of the metric, not a projection,
+// projections are `Line::project_point` and `Plane::reject_point`.
+pub(crate) fn inner<S, L, R, O>(lhs: &L, rhs: &R) -> O
+where
+    S: Scalar,
+    L: GeometricEntity<S>,
+    R: GeometricEntity<S>,
+    O: GeometricEntity<S>,
+{
+    let mut result = Multivector::default();
+    for r in 0..5 {
+        for s in 0..5 {
+            let product: Multivector<S> = geometric_product(&lhs.grade(r), &rhs.grade(s));
+            let grade = METRIC_GRADE[METRIC_GRADE[r].abs_diff(METRIC_GRADE[s])];
+            result = add_components(&result, &product.grade(grade));
+        }
+    }
+    copy_components(&result)
+}
+
+// This implement the wedge product (^) between different geometric entities, together with the
+// `Join` trait for the same pair so that the operator and the named form cannot drift apart.
 // It is implemented as a macro to get around some of the constraints of implementing
 // traits for generic types.
 macro_rules! impl_wedge {
-    ($a:ty, $b:ty, $out:ident) => {
-        impl BitXor<$b> for $a {
-            type Output = $out;
+    ($a:ident, $b:ident, $out:ident) => {
+        impl<S: Scalar> Join<$b<S>> for $a<S> {
+            type Output = $out<S>;
 
-            fn bitxor(self, rhs: $b) -> Self::Output {
+            fn join(self, rhs: $b<S>) -> Self::Output {
                 wedge(self, rhs)
             }
         }
+
+        impl<S: Scalar> BitXor<$b<S>> for $a<S> {
+            type Output = $out<S>;
+
+            fn bitxor(self, rhs: $b<S>) -> Self::Output {
+                self.join(rhs)
+            }
+        }
     };
 }
 macro_rules! impl_anti_wedge {
+    ($a:ident, $b:ident, $out:ident) => {
+        impl<S: Scalar> Meet<$b<S>> for $a<S> {
+            type Output = $out<S>;
+
+            fn meet(self, rhs: $b<S>) -> Self::Output {
+                antiwedge(self, rhs)
+            }
+        }
+
+        impl<S: Scalar> BitAnd<$b<S>> for $a<S> {
+            type Output = $out<S>;
+
+            fn bitand(self, rhs: $b<S>) -> Self::Output {
+                self.meet(rhs)
+            }
+        }
+    };
+}
+macro_rules! impl_geometric_product {
+    ($a:ident, $b:ident, $out:ident) => {
+        impl<S: Scalar> Mul<$b<S>> for $a<S> {
+            type Output = $out<S>;
+
+            fn mul(self, rhs: $b<S>) -> Self::Output {
+                geometric_product(&self, &rhs)
+            }
+        }
+    };
+}
+
+macro_rules! impl_inner {
+    ($a:ident, $b:ident, scalar) => {
+        impl<S: Scalar> BitOr<$b<S>> for $a<S> {
+            type Output = S;
+
+            fn bitor(self, rhs: $b<S>) -> Self::Output {
+                inner::<S, _, _, Multivector<S>>(&self, &rhs).scalar()
+            }
+        }
+    };
+    ($a:ident, $b:ident, $out:ident) => {
+        impl<S: Scalar> BitOr<$b<S>> for $a<S> {
+            type Output = $out<S>;
+
+            fn bitor(self, rhs: $b<S>) -> Self::Output {
+                inner(&self, &rhs)
+            }
+        }
+    };
+}
+
+// A plane direction has no exterior product with a point or a line, the grades would add up past
+// the antiscalar. Joining with it instead joins with its dual, the normal direction of the plane,
+// which is how the perpendicular constructions read: `line ^ plane.direction()` is the plane
+// through the line perpendicular to `plane`. `Plane & Direction` is left out, it only has a
+// scalar part.
+macro_rules! impl_wedge_dual {
     ($a:ty, $b:ty, $out:ident) => {
-        impl BitAnd<$b> for $a {
+        impl Join<$b> for $a {
             type Output = $out;
 
-            fn bitand(self, rhs: $b) -> Self::Output {
-                antiwedge(self, rhs)
+            fn join(self, rhs: $b) -> Self::Output {
+                self.join(!rhs)
+            }
+        }
+
+        impl BitXor<$b> for $a {
+            type Output = $out;
+
+            fn bitxor(self, rhs: $b) -> Self::Output {
+                self.join(rhs)
             }
         }
     };
@@ -144,10 +422,34 @@ impl_wedge!(Line, Direction, Plane);
 impl_wedge!(Point4, Direction, Line);
 impl_wedge!(Point3, Direction, Line);
 impl_wedge!(Direction, Point3, Line);
+impl_wedge!(Direction, Direction, LineMoment);
 impl_wedge!(Point4, LineMoment, Plane);
 impl_wedge!(Point3, LineMoment, Plane);
 impl_wedge!(Line, Point4, Plane);
+impl_wedge!(Plane, Point3, Multivector);
+impl_wedge!(Point3, Plane, Multivector);
+
+impl_wedge_dual!(Line, PlaneDirection, Plane);
+impl_wedge_dual!(Point3, PlaneDirection, Line);
+impl_wedge_dual!(Point4, PlaneDirection, Line);
 
 impl_anti_wedge!(Plane, Plane, Line);
 impl_anti_wedge!(Plane, Line, Point4);
 impl_anti_wedge!(Line, Plane, Point4);
+
+impl_inner!(Plane, Plane, scalar);
+impl_inner!(Line, Line, scalar);
+impl_inner!(Plane, Point3, Line);
+
+impl<S: Scalar> Point3<S> {
+    /// Joins three points into a plane, the same as `self ^ p1 ^ p2`.
+    pub fn join3(self, p1: Point3<S>, p2: Point3<S>) -> Plane<S> {
+        self ^ p1 ^ p2
+    }
+}
+
+impl_geometric_product!(Plane, Plane, Multivector);
+impl_geometric_product!(Line, Line, Multivector);
+impl_geometric_product!(Plane, Point3, Multivector);
+impl_geometric_product!(Motor, Motor, Motor);
+impl_geometric_product!(Multivector, Multivector, Multivector);
@@ -1,9 +1,13 @@
 #[cfg(test)]
 mod tests {
     use crate::pgai::{
-        BulkWeight, Direction, GeometricEntity, Line, Origin, Plane, PlaneDirection, Point3, Point4,
+        Bits, Blade, BulkWeight, Complement, Direction, GeometricEntity, GradeMismatch, Join, Line,
+        LineDirection, LinePlaneIntersection, Meet, Motor, Multivector, Origin, Plane,
+        PlaneDirection, Point3, Point4, PointOrDirection, Rotor, Transformable, Translator,
     };
     use crate::{ApproxEq, assert_approx_eq};
+    use glam::{Quat, Vec3, Vec4};
+    use std::cmp::Ordering;
 
     #[test]
     fn two_points_join_in_a_line() {
@@ -24,6 +28,29 @@ mod tests {
         assert!(degenerate_line1.is_zero());
     }
 
+    #[test]
+    fn line_from_point_and_direction() {
+        let origin = Point3::new(0.0, 0.0, 0.0);
+        let line = Line::from_point_direction(origin, Direction::new(2.0, 0.0, 0.0));
+        assert_approx_eq!(line.unitize(), Line::X_AXIS);
+
+        let direction = Direction::new(1.0, -2.0, 0.5);
+        let line = Line::from_point_direction(Point3::new(3.0, 1.0, -1.0), direction);
+        assert_approx_eq!(Vec3::from(line.direction()), Vec3::from(direction));
+    }
+
+    #[test]
+    fn line_from_two_points() {
+        let p0 = Point3::new(1.0, 2.0, 3.0);
+        let p1 = Point3::new(2.0, 2.0, 3.0);
+        assert_approx_eq!(Line::from_two_points(p0, p1).unwrap(), p0 ^ p1);
+        assert_approx_eq!(
+            Vec3::from(Line::from_two_points(p0, p1).unwrap().direction()),
+            Vec3::new(1.0, 0.0, 0.0)
+        );
+        assert_eq!(Line::from_two_points(p0, p0), None);
+    }
+
     #[test]
     fn three_points_join_in_a_plane() {
         let p0 = Point3::new(0.0, 0.0, 0.0);
@@ -47,6 +74,36 @@ mod tests {
         assert!(plane1.is_zero());
     }
 
+    #[test]
+    fn plane_from_three_points_is_unitized() {
+        let p0 = Point3::new(0.0, 0.0, 2.0);
+        let p1 = Point3::new(3.0, 0.0, 2.0);
+        let p2 = Point3::new(0.0, 3.0, 2.0);
+        let plane = Plane::from_points(p0, p1, p2).unwrap();
+        assert_approx_eq!(plane, (p0 ^ p1 ^ p2).unitize());
+        assert_approx_eq!(plane.weight_norm(), 1.0);
+    }
+
+    #[test]
+    fn plane_from_colinear_points_is_none() {
+        let p0 = Point3::new(0.0, 0.0, 0.0);
+        let p1 = Point3::new(1.0, 0.0, 0.0);
+        let p2 = Point3::new(2.0, 0.0, 0.0);
+        assert_eq!(Plane::from_points(p0, p1, p2), None);
+    }
+
+    #[test]
+    fn plane_from_coefficients_follows_the_equation() {
+        let plane = Plane::from_coefficients(1.0, 0.0, 0.0, -5.0);
+        assert!(plane.contains_point(&Point3::new(5.0, 0.0, 0.0), 1e-6));
+        assert!(plane.contains_point(&Point3::new(5.0, -2.0, 3.0), 1e-6));
+        assert!(!plane.contains_point(&Point3::new(-5.0, 0.0, 0.0), 1e-6));
+
+        let plane = Plane::from_coefficients(0.0, 2.0, 0.0, -4.0);
+        assert_approx_eq!(plane.distance_to_point(&Point3::new(0.0, 3.0, 0.0)), 1.0);
+        assert_approx_eq!(plane.unitize(), Plane::new(0.0, 1.0, 0.0, -2.0));
+    }
+
     #[test]
     fn two_planes_meet_in_a_line() {
         let line: Line = Plane::FORWARD & Plane::UP;
@@ -107,10 +164,11 @@ mod tests {
         let line: Line = p0 ^ p1;
         // Use a plane that doesn't pass through the origin: x = 0.5
         let plane = Plane::new(0.0, 0.0, 1.0, -0.5);
-        let point = plane & line;
         let expected = Direction::new(-0.5, 0.0, 0.0);
-        assert_approx_eq!(point.bulk(), expected);
-        assert_approx_eq!(point.weight(), Origin::new(0.0));
+        match line.meet_plane_classified(&plane) {
+            PointOrDirection::Direction(direction) => assert_approx_eq!(direction, expected),
+            PointOrDirection::Point(point) => panic!("expected a direction, got {point:?}"),
+        }
     }
 
     #[test]
@@ -194,4 +252,1318 @@ mod tests {
 
         assert_eq!(projected_point, Point3::new(0.0, 0.0, 0.0));
     }
+
+    #[test]
+    fn dot_is_the_scalar_part_of_the_product_with_the_reverse() {
+        let left = Plane::LEFT;
+        assert_approx_eq!(GeometricEntity::dot(&left, &left), left.length_squared());
+        assert_approx_eq!(GeometricEntity::dot(&left, &Plane::UP), 0.0);
+        assert_approx_eq!(
+            GeometricEntity::dot(&Line::X_AXIS, &Line::X_AXIS),
+            Line::X_AXIS.length_squared()
+        );
+
+        // The offset from the origin does not contribute
+        let plane = Plane::new(1.0, 2.0, 2.0, 5.0);
+        assert_approx_eq!(GeometricEntity::dot(&plane, &plane), 9.0);
+        let direction = Direction::new(1.0, 0.0, 0.0);
+        assert_approx_eq!(GeometricEntity::dot(&direction, &direction), 0.0);
+        let point = Point3::new(1.0, 2.0, 3.0);
+        assert_approx_eq!(GeometricEntity::dot(&point, &point), 1.0);
+    }
+
+    #[test]
+    fn unit_plane_squares_to_one() {
+        let square = Plane::LEFT * Plane::LEFT;
+        let one = Multivector {
+            scalar: 1.0,
+            ..Default::default()
+        };
+        assert_approx_eq!(square, one);
+    }
+
+    #[test]
+    fn orthogonal_planes_multiply_to_their_meet() {
+        let product = Plane::LEFT * Plane::UP;
+        let meet = Plane::LEFT & Plane::UP;
+        assert_approx_eq!(product.scalar(), 0.0);
+        assert_approx_eq!(product.e41(), meet.vx);
+        assert_approx_eq!(product.e42(), meet.vy);
+        assert_approx_eq!(product.e43(), meet.vz);
+    }
+
+    #[test]
+    fn line_product_keeps_the_antiscalar_of_skew_lines() {
+        // The y direction through (0, 0, 1) passes above the x axis without meeting it
+        let above = Point3::new(0.0, 0.0, 1.0) ^ Point3::new(0.0, 1.0, 1.0);
+        let skew = Line::X_AXIS * above;
+        assert_approx_eq!(skew.scalar(), Line::X_AXIS | above);
+        assert!(skew.antiscalar().abs() > 0.5);
+        assert!(!Line::X_AXIS.is_coplanar(&above, 1e-3));
+
+        let crossing = Line::X_AXIS * Line::Y_AXIS;
+        assert_approx_eq!(crossing.antiscalar(), 0.0);
+    }
+
+    #[test]
+    fn four_planes_multiply_to_a_screw_motor() {
+        // Reflections in two planes through the z axis rotate about it, reflections in two
+        // planes perpendicular to it translate along it. Together they give an antiscalar,
+        // which no product of two planes has.
+        let diagonal = Plane::new(1.0, 1.0, 0.0, 0.0).normalize();
+        let rotation = diagonal * Plane::LEFT;
+        let translation = Plane::new(0.0, 0.0, 1.0, -1.0) * Plane::FORWARD;
+        assert_approx_eq!(rotation.antiscalar(), 0.0);
+        assert_approx_eq!(translation.antiscalar(), 0.0);
+
+        let screw = rotation * translation;
+        assert!(screw.antiscalar().abs() > 0.1);
+        let motor = Motor::try_from(screw).unwrap();
+        assert_approx_eq!(motor.uw, screw.antiscalar());
+
+        // The antiscalar survives normalization, which only removes the drift that M M̃ would
+        // carry in its own antiscalar.
+        let unit = motor.normalize();
+        assert_approx_eq!(unit, motor, 1e-5);
+        assert_approx_eq!(unit.compose(&unit.reverse()), Motor::IDENTITY, 1e-5);
+    }
+
+    #[test]
+    fn line_transform_keeps_the_plucker_constraint() {
+        // A rotation about an axis away from the origin, so that repeating it keeps the line in
+        // a bounded region while both its direction and its moment change
+        let axis = Line::from_plucker([0.3, -0.8, 0.5], [1.4, -0.35, -1.4]);
+        let motor = Motor::from_rotation(axis, 0.731).normalize();
+        let mut line = Line::X_AXIS;
+        for _ in 0..1000 {
+            line = line.transform(&motor);
+            assert!(line.plucker_constraint().abs() < 1e-5);
+        }
+        assert_approx_eq!(line.direction().norm(), 1.0, 1e-3);
+
+        let moved = Line::X_AXIS.transform(&motor);
+        assert_approx_eq!(moved, motor.transform(&Line::X_AXIS), 1e-5);
+        assert_approx_eq!(Line::X_AXIS.transform_by(&motor), moved);
+    }
+
+    #[test]
+    fn transform_by_moves_points_but_only_rotates_directions() {
+        let translation = Motor::from(Translator::new(Vec3::new(1.0, 2.0, 3.0)));
+        let direction = Direction::new(0.0, 0.0, 1.0);
+        assert_approx_eq!(direction.transform_by(&translation), direction);
+        let point = Point3::new(1.0, 0.0, 0.0);
+        assert_approx_eq!(point.transform_by(&translation), Point3::new(2.0, 2.0, 3.0));
+
+        let quarter_turn = Motor::from_rotation(Line::Z_AXIS, std::f32::consts::FRAC_PI_2);
+        let screw = translation.compose(&quarter_turn);
+        let turned = Direction::new(1.0, 0.0, 0.0).transform_by(&screw);
+        assert_approx_eq!(turned, Direction::new(0.0, 1.0, 0.0), 1e-5);
+        assert_approx_eq!(
+            Plane::UP.transform_by(&translation),
+            translation.transform(&Plane::UP)
+        );
+    }
+
+    #[test]
+    fn half_rotations_compose_to_a_full_rotation() {
+        let half = Motor::from_rotation(Line::Z_AXIS, std::f32::consts::FRAC_PI_4);
+        let full = Motor::from_rotation(Line::Z_AXIS, std::f32::consts::FRAC_PI_2);
+        assert_approx_eq!(half.compose(&half), full);
+    }
+
+    #[test]
+    fn translations_compose_by_adding_offsets() {
+        let a = Motor::from_translation(Vec3::new(1.0, 0.0, 0.0));
+        let b = Motor::from_translation(Vec3::new(0.0, 2.0, 3.0));
+        let expected = Motor::from_translation(Vec3::new(1.0, 2.0, 3.0));
+        assert_approx_eq!(a.compose(&b), expected);
+        assert_approx_eq!(Motor::identity().compose(&a), a);
+    }
+
+    #[test]
+    fn motor_translates_a_point() {
+        let motor = Motor::from_translation(Vec3::new(1.0, 2.0, 3.0));
+        let moved = motor.transform(&Point3::new(0.0, 0.0, 0.0));
+        assert_approx_eq!(moved, Point3::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn motor_rotates_a_point() {
+        let motor = Motor::from_rotation(Line::Z_AXIS, std::f32::consts::FRAC_PI_2);
+        let rotated = motor.transform(&Point3::new(1.0, 0.0, 0.0));
+        assert_approx_eq!(rotated, Point3::new(0.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn motor_preserves_the_grade_of_planes_and_lines() {
+        let motor = Motor::from_rotation(Line::Z_AXIS, std::f32::consts::FRAC_PI_2);
+        assert_approx_eq!(motor.transform(&Plane::LEFT), Plane::UP);
+        assert_approx_eq!(motor.transform(&Line::X_AXIS), Line::Y_AXIS);
+
+        let motor = Motor::from_translation(Vec3::new(0.0, 0.0, 1.0));
+        let line: Line = Point3::new(0.0, 0.0, 1.0) ^ Point3::new(1.0, 0.0, 1.0);
+        assert_approx_eq!(motor.transform(&Line::X_AXIS), line);
+    }
+
+    #[test]
+    fn exp_of_a_scaled_axis_rotates_a_point() {
+        let angle = std::f32::consts::FRAC_PI_2;
+        let motor = Line::through_origin(0.0, 0.0, angle * 0.5).exp();
+        assert_approx_eq!(motor, Motor::from_rotation(Line::Z_AXIS, angle));
+        let rotated = motor.transform(&Point3::new(1.0, 0.0, 0.0));
+        assert_approx_eq!(rotated, Point3::new(0.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn exp_of_an_ideal_line_translates() {
+        let motor = Line::new(0.0, 0.0, 0.0, 0.5, 1.0, 1.5).exp();
+        assert_eq!(motor, Motor::from_translation(Vec3::new(1.0, 2.0, 3.0)));
+        let moved = motor.transform(&Point3::new(1.0, 0.0, 0.0));
+        assert_approx_eq!(moved, Point3::new(2.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn exp_of_a_screw_line_rotates_and_translates_along_it() {
+        let angle = std::f32::consts::FRAC_PI_2;
+        let motor = Line::new(0.0, 0.0, angle * 0.5, 0.0, 0.0, 1.0).exp();
+        let expected = Motor::from_translation(Vec3::new(0.0, 0.0, 2.0))
+            .compose(&Motor::from_rotation(Line::Z_AXIS, angle));
+        assert_approx_eq!(motor, expected);
+    }
+
+    #[test]
+    fn log_inverts_exp() {
+        let line = Line::new(0.3, -0.5, 0.7, 0.2, 0.4, -0.1);
+        let motor = line.exp();
+        assert_approx_eq!(motor.log(), line, 1e-5);
+        assert_approx_eq!(motor.log().exp(), motor, 1e-5);
+    }
+
+    #[test]
+    fn log_of_identity_and_translation() {
+        assert_eq!(Motor::IDENTITY.log(), Line::default());
+        let line = Motor::from_translation(Vec3::new(1.0, 2.0, 3.0)).log();
+        assert!(line.weight().is_zero());
+        assert_eq!(line, Line::new(0.0, 0.0, 0.0, 0.5, 1.0, 1.5));
+    }
+
+    #[test]
+    fn inverse_undoes_a_motor() {
+        let motor = Motor::from_rotation(Line::X_AXIS, 0.7)
+            .compose(&Motor::from_translation(Vec3::new(1.0, -2.0, 0.5)));
+        assert_approx_eq!(motor.compose(&motor.inverse()), Motor::IDENTITY, 1e-5);
+    }
+
+    #[test]
+    fn slerp_halfway_to_a_quarter_turn_is_an_eighth_turn() {
+        let a = Motor::IDENTITY;
+        let b = Motor::from_rotation(Line::Z_AXIS, std::f32::consts::FRAC_PI_2);
+        assert_approx_eq!(a.slerp(&b, 0.0), a);
+        assert_approx_eq!(a.slerp(&b, 1.0), b);
+        let halfway = Motor::from_rotation(Line::Z_AXIS, std::f32::consts::FRAC_PI_4);
+        assert_approx_eq!(a.slerp(&b, 0.5), halfway);
+    }
+
+    #[test]
+    fn to_mat4_agrees_with_transform() {
+        let motor = Motor::from_translation(Vec3::new(1.0, -2.0, 0.5))
+            .compose(&Motor::from_rotation(
+                Line::new(-0.4, 0.7, 0.3, 0.0, 0.0, 0.0),
+                0.9,
+            ))
+            .compose(&Motor::from_rotation(Line::X_AXIS, 2.0));
+        let matrix = motor.to_mat4();
+        for point in [
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 0.0, 0.0),
+            Point3::new(0.0, -3.0, 2.0),
+            Point3::new(4.0, 5.0, -6.0),
+        ] {
+            let expected = Vec3::from(motor.transform(&point));
+            let actual = matrix.transform_point3(Vec3::from(point));
+            assert_approx_eq!(actual, expected, 1e-5);
+        }
+    }
+
+    #[test]
+    fn normalize_makes_a_scaled_motor_rigid() {
+        let motor = Motor::from_rotation(Line::Z_AXIS, 1.0)
+            .compose(&Motor::from_translation(Vec3::new(1.0, -2.0, 0.5)));
+        let scaled = Motor::new(
+            motor.rx * 3.0,
+            motor.ry * 3.0,
+            motor.rz * 3.0,
+            motor.rw * 3.0,
+            motor.ux * 3.0 + 0.1,
+            motor.uy * 3.0,
+            motor.uz * 3.0,
+            motor.uw * 3.0 + 0.2,
+        );
+        let unit = scaled.normalize();
+        assert_approx_eq!(unit.compose(&unit.reverse()), Motor::IDENTITY, 1e-5);
+    }
+
+    #[test]
+    fn perpendicular_from_a_point_to_the_z_axis() {
+        let point = Point3::new(3.0, 4.0, 5.0);
+        let perpendicular = Line::Z_AXIS.perpendicular_through(&point);
+        let expected = Line::from_two_points(point, Point3::new(0.0, 0.0, 5.0)).unwrap();
+        assert_approx_eq!(perpendicular, expected);
+        assert_approx_eq!(
+            perpendicular.angle_to(&Line::Z_AXIS),
+            std::f32::consts::FRAC_PI_2
+        );
+
+        let on_axis = Point3::new(0.0, 0.0, 2.0);
+        assert!(Line::Z_AXIS.perpendicular_through(&on_axis).is_zero());
+    }
+
+    #[test]
+    fn plane_perpendicular_through_a_line() {
+        let plane = Plane::new(1.0, 0.0, 1.0, 1.0);
+        let line: Line = Point3::new(1.0, 0.0, 0.0) ^ Point3::new(0.0, 1.0, 0.0);
+        let perpendicular = plane.perpendicular_through(&line);
+        assert!(perpendicular.contains_line(&line, 1e-5));
+        assert_approx_eq!(perpendicular.angle_to(&plane), std::f32::consts::FRAC_PI_2);
+
+        let along_normal =
+            Line::from_point_direction(Point3::new(0.0, 1.0, 0.0), Direction::new(1.0, 0.0, 1.0));
+        assert!(plane.perpendicular_through(&along_normal).is_zero());
+    }
+
+    #[test]
+    fn plane_reflects_a_point() {
+        let mirror = Plane::new(1.0, 0.0, 0.0, 0.0);
+        let reflected = mirror.reflect(&Point3::new(1.0, 0.0, 0.0));
+        assert_approx_eq!(reflected, Point3::new(-1.0, 0.0, 0.0));
+
+        let mirror = Plane::new(0.0, 1.0, 0.0, -1.0); // y = 1
+        let reflected = mirror.reflect(&Point3::new(2.0, 3.0, 4.0));
+        assert_approx_eq!(reflected, Point3::new(2.0, -1.0, 4.0));
+    }
+
+    #[test]
+    fn plane_reflected_across_itself_is_unchanged() {
+        let plane = Plane::new(1.0, 2.0, -2.0, 3.0).unitize();
+        assert_approx_eq!(plane.reflect(&plane), plane);
+    }
+
+    #[test]
+    fn plane_reflects_a_line() {
+        let mirror = Plane::new(1.0, 0.0, 0.0, -1.0); // x = 1
+        let line: Line = Point3::new(0.0, 0.0, 0.0) ^ Point3::new(0.0, 1.0, 0.0);
+        let expected: Line = Point3::new(2.0, 0.0, 0.0) ^ Point3::new(2.0, 1.0, 0.0);
+        // The sandwich flips the orientation of reflected lines.
+        assert_approx_eq!(mirror.reflect(&line), -expected);
+
+        // A line crossing the mirror keeps the crossing point.
+        let line: Line = Point3::new(0.0, 0.0, 0.0) ^ Point3::new(2.0, 1.0, 0.0);
+        let expected: Line = Point3::new(2.0, 0.0, 0.0) ^ Point3::new(0.0, 1.0, 0.0);
+        assert_approx_eq!(mirror.reflect(&line), -expected);
+    }
+
+    #[test]
+    fn reverse_flips_the_bivector_components_of_a_line() {
+        let line = Line::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+        assert_eq!(line.reverse(), -line);
+        assert_eq!(line.reverse().reverse(), line);
+        assert_eq!(line.grade_involution(), line);
+    }
+
+    #[test]
+    fn reverse_and_grade_involution_follow_the_grade() {
+        let plane = Plane::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(plane.reverse(), -plane);
+        assert_eq!(plane.grade_involution(), -plane);
+
+        let point = Point4::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(point.reverse(), point);
+        assert_eq!(point.grade_involution(), -point);
+
+        let motor = Motor::from_rotation(Line::X_AXIS, 1.0);
+        assert_eq!(motor.reverse().reverse(), motor);
+    }
+
+    #[test]
+    fn grade_extracts_a_pure_plane() {
+        let plane = Plane::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(plane.grade(3), plane);
+        assert!(plane.grade(2).is_zero());
+        assert_eq!(plane.grades(), 1 << 3);
+    }
+
+    #[test]
+    fn grades_reports_the_mixed_grades_of_a_product() {
+        let meet = Plane::LEFT & Plane::UP;
+        assert_eq!(meet.grades(), 1 << 2);
+
+        let tilted = Plane::new(1.0, 1.0, 0.0, 0.0);
+        let product = Plane::LEFT * tilted;
+        assert_eq!(product.grades(), 1 << 0 | 1 << 2);
+        assert_approx_eq!(product.grade(0).scalar(), 1.0);
+        assert_eq!(product.grade(2).grades(), 1 << 2);
+    }
+
+    #[test]
+    fn triangle_area_and_tetra_volume() {
+        let origin = Point3::new(0.0, 0.0, 0.0);
+        let x = Point3::new(1.0, 0.0, 0.0);
+        let y = Point3::new(0.0, 1.0, 0.0);
+        let z = Point3::new(0.0, 0.0, 1.0);
+        assert_approx_eq!(Point3::triangle_area(x, y, z), 3.0_f32.sqrt() / 2.0);
+        assert_approx_eq!(Point3::triangle_area(y, x, z), 3.0_f32.sqrt() / 2.0);
+        assert_approx_eq!(Point3::triangle_area(origin, x, x), 0.0);
+
+        assert_approx_eq!(Point3::tetra_volume(origin, x, y, z), -1.0 / 6.0);
+        assert_approx_eq!(Point3::tetra_volume(origin, y, x, z), 1.0 / 6.0);
+        let stretched = Point3::new(0.0, 0.0, -3.0);
+        assert_approx_eq!(Point3::tetra_volume(origin, x, y, stretched), 0.5);
+    }
+
+    #[test]
+    fn mass_points_sum_to_their_center_of_mass() {
+        let left = Point3::new(0.0, 0.0, 0.0);
+        let right = Point3::new(2.0, 0.0, 0.0);
+        let equal = Point4::sum(&[Point4::weighted(left, 1.0), Point4::weighted(right, 1.0)]);
+        assert_approx_eq!(equal.w, 2.0);
+        assert_approx_eq!(equal.project(), Point3::new(1.0, 0.0, 0.0));
+
+        let heavy_right = Point4::sum(&[Point4::weighted(left, 1.0), Point4::weighted(right, 3.0)]);
+        assert_approx_eq!(heavy_right.project(), Point3::new(1.5, 0.0, 0.0));
+        assert_eq!(Point4::sum(&[]), Point4::default());
+    }
+
+    #[test]
+    fn plane_normal_and_distance_are_unitized() {
+        let plane = Plane::new(0.0, 2.0, 0.0, -4.0); // y = 2
+        assert_approx_eq!(plane.normal(), Vec3::new(0.0, 1.0, 0.0));
+        assert_approx_eq!(plane.distance(), 2.0);
+        assert_approx_eq!((-plane).normal(), Vec3::new(0.0, -1.0, 0.0));
+        assert_approx_eq!((-plane).distance(), -2.0);
+
+        let horizon = Plane::new(0.0, 0.0, 0.0, 1.0);
+        assert_eq!(horizon.normal(), Vec3::ZERO);
+        assert!(horizon.distance().is_nan());
+    }
+
+    #[test]
+    fn side_of_plane_follows_the_normal() {
+        let plane = Plane::new(0.0, 0.0, 2.0, -2.0); // z = 1
+        assert_eq!(
+            plane.side_of(&Point3::new(5.0, 0.0, 3.0), 1e-5),
+            Ordering::Greater
+        );
+        assert_eq!(
+            plane.side_of(&Point3::new(5.0, 0.0, -3.0), 1e-5),
+            Ordering::Less
+        );
+        assert_eq!(
+            plane.side_of(&Point3::new(5.0, 0.0, 1.0), 1e-5),
+            Ordering::Equal
+        );
+        assert_eq!(
+            (-plane).side_of(&Point3::new(5.0, 0.0, 3.0), 1e-5),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn orient3d_gives_the_signed_volume() {
+        let origin = Point3::new(0.0, 0.0, 0.0);
+        let x = Point3::new(1.0, 0.0, 0.0);
+        let y = Point3::new(0.0, 1.0, 0.0);
+        let z = Point3::new(0.0, 0.0, 1.0);
+        // origin ^ x ^ y is the plane z = 0 with its normal along +z
+        assert_approx_eq!(Point3::orient3d(origin, x, y, z), -1.0);
+        assert_approx_eq!(Point3::orient3d(origin, y, x, z), 1.0);
+        let below = Point3::new(0.0, 0.0, -2.0);
+        assert_approx_eq!(Point3::orient3d(origin, x, y, below), 2.0);
+        assert_approx_eq!(
+            Point3::orient3d(origin, x, y, Point3::new(3.0, -2.0, 0.0)),
+            0.0
+        );
+    }
+
+    #[test]
+    fn signed_distance_between_point_and_plane() {
+        let plane = Plane::new(1.0, 0.0, 0.0, -5.0); // x = 5
+        let point = Point3::new(2.0, 0.0, 0.0);
+        assert_approx_eq!(plane.distance_to_point(&point), -3.0);
+        assert_approx_eq!(point.distance_to_plane(&plane), -3.0);
+
+        let scaled = Plane::new(0.0, 0.0, 2.0, -2.0); // z = 1
+        assert_approx_eq!(scaled.distance_to_point(&Point3::new(7.0, 7.0, 4.0)), 3.0);
+    }
+
+    #[test]
+    fn distance_to_plane_without_normal_is_nan() {
+        let horizon = Plane::new(0.0, 0.0, 0.0, 1.0);
+        assert!(
+            horizon
+                .distance_to_point(&Point3::new(1.0, 2.0, 3.0))
+                .is_nan()
+        );
+    }
+
+    #[test]
+    fn distance_between_points() {
+        let p0 = Point3::new(1.0, 2.0, 3.0);
+        let p1 = Point3::new(4.0, 6.0, 3.0);
+        assert_approx_eq!(p0.distance(&p1), 5.0);
+        assert_approx_eq!(p1.distance(&p0), 5.0);
+    }
+
+    #[test]
+    fn distance_between_point_and_line() {
+        assert_approx_eq!(
+            Point3::new(0.0, 1.0, 0.0).distance_to_line(&Line::X_AXIS),
+            1.0
+        );
+
+        // A scaled line that does not pass through the origin: y = 2 in the z = 1 plane.
+        let line: Line = Point3::new(0.0, 2.0, 1.0) ^ Point3::new(3.0, 2.0, 1.0);
+        let point = Point3::new(-5.0, 5.0, 5.0);
+        assert_approx_eq!(point.distance_to_line(&line), 5.0, 1e-5);
+        assert!(point.distance_to_line(&Line::default()).is_nan());
+    }
+
+    #[test]
+    fn project_point_onto_line() {
+        let foot = Line::X_AXIS.project_point(&Point3::new(3.0, 4.0, 0.0));
+        assert_approx_eq!(foot, Point3::new(3.0, 0.0, 0.0));
+
+        let line: Line = Point3::new(0.0, 2.0, 1.0) ^ Point3::new(3.0, 2.0, 1.0);
+        let foot = line.project_point(&Point3::new(-5.0, 5.0, 5.0));
+        assert_approx_eq!(foot, Point3::new(-5.0, 2.0, 1.0), 1e-5);
+
+        let foot = Line::default().project_point(&Point3::new(1.0, 0.0, 0.0));
+        assert!(foot.x.is_nan());
+    }
+
+    #[test]
+    fn reject_point_from_plane() {
+        let plane = Plane::new(1.0, 0.0, 0.0, 0.0);
+        let rejection = plane.reject_point(&Point3::new(2.0, 0.0, 0.0));
+        assert_approx_eq!(rejection, Direction::new(2.0, 0.0, 0.0));
+
+        let plane = Plane::new(0.0, 2.0, 0.0, -2.0);
+        let rejection = plane.reject_point(&Point3::new(3.0, -1.0, 4.0));
+        assert_approx_eq!(rejection, Direction::new(0.0, -2.0, 0.0));
+
+        let rejection = Plane::default().reject_point(&Point3::new(1.0, 1.0, 1.0));
+        assert_eq!(rejection, Direction::default());
+    }
+
+    #[test]
+    fn angle_between_planes() {
+        use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+        assert_approx_eq!(Plane::LEFT.angle_to(&Plane::UP), FRAC_PI_2);
+        assert_approx_eq!(Plane::LEFT.angle_to(&Plane::new(3.0, 0.0, 0.0, 1.0)), 0.0);
+        assert_approx_eq!(Plane::LEFT.angle_to(&-Plane::LEFT), PI);
+        assert_approx_eq!(
+            Plane::UP.angle_to(&Plane::new(0.0, 1.0, 1.0, 0.0)),
+            FRAC_PI_4
+        );
+    }
+
+    #[test]
+    fn angle_between_lines() {
+        let parallel: Line = Point3::new(0.0, 1.0, 0.0) ^ Point3::new(2.0, 1.0, 0.0);
+        assert_approx_eq!(Line::X_AXIS.angle_to(&parallel), 0.0);
+        assert_approx_eq!(
+            Line::X_AXIS.angle_to(&Line::Z_AXIS),
+            std::f32::consts::FRAC_PI_2
+        );
+    }
+
+    #[test]
+    fn two_points_join_in_a_line_with_named_method() {
+        let p0 = Point3::new(0.0, 0.0, 0.0);
+        let p1 = Point3::new(1.0, 0.0, 0.0);
+        let line: Line = p0.join(p1);
+        assert!(!line.is_zero());
+        assert_eq!(line, p0 ^ p1);
+    }
+
+    #[test]
+    fn named_join_and_meet_match_the_operators() {
+        let p0 = Point3::new(0.0, 0.0, 0.0);
+        let p1 = Point3::new(1.0, 0.0, 0.0);
+        let p2 = Point3::new(0.0, 1.0, 0.0);
+        assert_eq!(p0.join3(p1, p2), p0 ^ p1 ^ p2);
+        assert_eq!(p0.join(p1).join(p2), p0 ^ p1 ^ p2);
+
+        let line = Plane::LEFT.meet(Plane::UP);
+        assert_eq!(line, Plane::LEFT & Plane::UP);
+        assert_eq!(Plane::FORWARD.meet(line), Plane::FORWARD & line);
+        assert_eq!(line.meet(Plane::FORWARD), line & Plane::FORWARD);
+    }
+
+    #[test]
+    fn join_and_meet_traits_work_in_generic_code() {
+        fn join_all<A: Join<B>, B, C>(a: A, b: B, c: C) -> <A::Output as Join<C>>::Output
+        where
+            A::Output: Join<C>,
+        {
+            a.join(b).join(c)
+        }
+        fn meet_all<A: Meet<B>, B, C>(a: A, b: B, c: C) -> <A::Output as Meet<C>>::Output
+        where
+            A::Output: Meet<C>,
+        {
+            a.meet(b).meet(c)
+        }
+
+        let p0 = Point3::new(0.0, 0.0, 0.0);
+        let p1 = Point3::new(1.0, 0.0, 0.0);
+        let p2 = Point3::new(0.0, 1.0, 0.0);
+        let plane: Plane = join_all(p0, p1, p2);
+        assert_eq!(plane, Plane::FORWARD);
+
+        let point: Point4 = meet_all(
+            Plane::new(1.0, 0.0, 0.0, -1.0),
+            Plane::new(0.0, 1.0, 0.0, -2.0),
+            Plane::new(0.0, 0.0, 1.0, -3.0),
+        );
+        assert_approx_eq!(Point3::from(point), Point3::new(1.0, 2.0, 3.0));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn line_round_trips_through_json() {
+        let line: Line = Point3::new(1.0, 2.0, 3.0) ^ Point3::new(-1.0, 0.5, 2.0);
+        let json = serde_json::to_string(&line).unwrap();
+        assert!(json.contains("\"vx\"") && json.contains("\"mz\""));
+        let parsed: Line = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, line);
+    }
+
+    #[test]
+    fn display_uses_basis_elements() {
+        assert_eq!(format!("{}", Line::X_AXIS), "1.0 e41");
+        assert_eq!(
+            format!("{}", Line::new(1.0, 0.0, 0.0, 2.0, -0.5, 0.0)),
+            "1.0 e41 + 2.0 e23 - 0.5 e31"
+        );
+        assert_eq!(
+            format!("{}", Plane::new(-1.0, 0.0, 0.0, 3.0)),
+            "-1.0 e423 + 3.0 e321"
+        );
+        assert_eq!(format!("{}", Point3::new(2.0, 0.0, 0.0)), "2.0 e1 + 1.0 e0");
+        assert_eq!(format!("{}", Motor::IDENTITY), "1.0");
+        assert_eq!(format!("{}", Plane::<f32>::default()), "0");
+    }
+
+    #[test]
+    fn f64_points_join_in_a_line() {
+        // Points one unit apart far from the origin collapse in f32 but stay distinct in f64.
+        let p0: Point3<f64> = Point3::new(1.0e8, 0.0, 0.0);
+        let p1: Point3<f64> = Point3::new(1.0e8 + 1.0, 0.0, 0.0);
+        let line: Line<f64> = p0 ^ p1;
+        assert_eq!(line, Line::new(1.0, 0.0, 0.0, 0.0, 0.0, 0.0));
+        assert!(!line.is_zero());
+        assert!((line.norm() - 1.0).abs() < f64::EPSILON);
+
+        let p0 = Point3::new(1.0e8_f32, 0.0, 0.0);
+        let p1 = Point3::new(1.0e8_f32 + 1.0, 0.0, 0.0);
+        assert!((p0 ^ p1).is_zero());
+    }
+
+    #[test]
+    fn plane_round_trips_through_multivector() {
+        let plane = Plane::new(1.0, -2.0, 0.5, 3.0);
+        let multivector = Multivector::from(plane);
+        assert_eq!(multivector.e431, -2.0);
+        assert_eq!(Plane::try_from(multivector), Ok(plane));
+        assert_eq!(Line::try_from(multivector), Err(GradeMismatch));
+    }
+
+    #[test]
+    fn point3_from_multivector_requires_unit_weight() {
+        let point = Point3::new(1.0, 2.0, 3.0);
+        assert_eq!(Point3::try_from(Multivector::from(point)), Ok(point));
+        let weighted = Multivector::from(Point4::new(1.0, 2.0, 3.0, 2.0));
+        assert_eq!(Point3::try_from(weighted), Err(GradeMismatch));
+    }
+
+    #[test]
+    fn bulk_and_weight_norms() {
+        let horizon = Plane::new(0.0, 0.0, 0.0, 5.0);
+        assert_eq!(horizon.bulk_norm(), 5.0);
+        assert_eq!(horizon.weight_norm(), 0.0);
+
+        let plane = Plane::new(0.0, 2.0, 0.0, -6.0);
+        assert_approx_eq!(plane.unitize().weight_norm(), 1.0);
+        assert_approx_eq!(plane.geometric_norm(), 3.0);
+
+        let line: Line = Point3::new(0.0, 2.0, 1.0) ^ Point3::new(3.0, 2.0, 1.0);
+        assert_approx_eq!(line.unitize().weight_norm(), 1.0);
+        assert_approx_eq!(line.geometric_norm(), 5.0_f32.sqrt());
+
+        let point = Point4::new(3.0, 0.0, 4.0, 2.0);
+        assert_approx_eq!(point.geometric_norm(), 2.5);
+    }
+
+    #[test]
+    fn plane_inverse_undoes_the_plane() {
+        assert_eq!(Plane::LEFT.inverse(), Some(Plane::LEFT));
+        assert_eq!(Plane::new(0.0, 0.0, 0.0, 5.0).inverse(), None);
+
+        let one = Multivector {
+            scalar: 1.0,
+            ..Default::default()
+        };
+        let plane = Plane::new(1.0, -2.0, 2.0, 3.0);
+        assert_approx_eq!(plane * plane.inverse().unwrap(), one);
+    }
+
+    #[test]
+    fn line_inverse_undoes_the_line() {
+        let one = Multivector {
+            scalar: 1.0,
+            ..Default::default()
+        };
+        let line: Line = Point3::new(0.0, 2.0, 1.0) ^ Point3::new(3.0, 2.0, -1.0);
+        assert_approx_eq!(line * line.inverse().unwrap(), one);
+        assert_eq!(Line::new(0.0, 0.0, 0.0, 1.0, 2.0, 0.0).inverse(), None);
+    }
+
+    #[test]
+    fn unit_directions_are_none_at_infinity() {
+        assert_eq!(
+            Line::new(0.0, 0.0, 0.0, 1.0, 2.0, 0.0).direction_unit(),
+            None
+        );
+        let line: Line = Point3::new(1.0, 1.0, 0.0) ^ Point3::new(1.0, 4.0, 4.0);
+        let direction = Vec3::from(line.direction_unit().unwrap());
+        assert!(direction.abs_diff_eq(Vec3::new(0.0, 0.6, 0.8), 1e-6));
+
+        assert_eq!(Plane::new(0.0, 0.0, 0.0, 5.0).normal_unit(), None);
+        let normal = Plane::new(0.0, -3.0, 4.0, 2.0).normal_unit().unwrap();
+        assert!(normal.abs_diff_eq(Vec3::new(0.0, -0.6, 0.8), 1e-6));
+
+        // Nearly ideal entities count as ideal under a looser tolerance
+        let nearly_ideal = Line::new(1e-6, 0.0, 0.0, 0.0, 1.0, 0.0);
+        assert!(nearly_ideal.direction_unit().is_some());
+        assert_eq!(nearly_ideal.direction_unit_eps(1e-5), None);
+        let nearly_horizon = Plane::new(0.0, 1e-6, 0.0, 1.0);
+        assert!(nearly_horizon.normal_unit().is_some());
+        assert_eq!(nearly_horizon.normal_unit_eps(1e-5), None);
+
+        // The default tolerance is the machine epsilon, reached exactly here
+        let at_epsilon = Line::new(f32::EPSILON, 0.0, 0.0, 0.0, 1.0, 0.0);
+        assert!(at_epsilon.is_ideal());
+        assert_eq!(at_epsilon.direction_unit(), None);
+    }
+
+    #[test]
+    fn indexing_by_blade_matches_the_getters() {
+        let mut line = Line::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+        assert_eq!(line[Blade::E41], line.e41());
+        line[Blade::E41] = 7.0;
+        assert_eq!(line.e41(), 7.0);
+        assert_eq!(line[Blade::E41], 7.0);
+
+        let multivector: Multivector = line.into();
+        for blade in Blade::ALL {
+            let expected = match blade {
+                Blade::E41 | Blade::E42 | Blade::E43 | Blade::E23 | Blade::E31 | Blade::E12 => {
+                    line[blade]
+                }
+                _ => 0.0,
+            };
+            assert_eq!(multivector[blade], expected);
+        }
+    }
+
+    #[test]
+    fn indexing_reads_fixed_and_absent_components_through_the_getters() {
+        let mut point = Point3::new(1.0, 2.0, 3.0);
+        assert_eq!(point[Blade::E0], 1.0);
+        assert_eq!(point[Blade::E0], point.e0());
+        assert_eq!(point[Blade::E321], 0.0);
+        assert_eq!(point.get(Blade::E2), 2.0);
+        assert_eq!(point.get_mut(Blade::E0), None);
+        *point.get_mut(Blade::E2).unwrap() = 5.0;
+        assert_eq!(point.y, 5.0);
+
+        let mut line = Line::X_AXIS;
+        assert_eq!(line[Blade::E0], 0.0);
+        assert_eq!(line.get_mut(Blade::E0), None);
+        assert_eq!(line.get_mut(Blade::E41), Some(&mut 1.0));
+    }
+
+    #[test]
+    fn unitize_leaves_ideal_entities_unchanged() {
+        let horizon = Plane::new(0.0, 0.0, 0.0, 5.0);
+        let unitized = horizon.unitize();
+        assert_eq!(unitized, horizon);
+        assert!(unitized.blades().all(|(_, value)| value.is_finite()));
+
+        let ideal_line = Line::from_plucker([0.0, 0.0, 0.0], [0.0, 0.0, 2.0]);
+        assert_eq!(ideal_line.unitize(), ideal_line);
+        assert_eq!(Plane::default().unitize(), Plane::default());
+    }
+
+    #[test]
+    fn unitize_point4_moves_it_to_unit_weight() {
+        let point = Point4::new(2.0, -4.0, 6.0, -2.0).unitize();
+        assert_eq!(point, Point4::new(-1.0, 2.0, -3.0, 1.0));
+        assert!(!point.is_ideal());
+
+        let direction = Point4::new(1.0, 2.0, 3.0, 0.0);
+        assert!(direction.is_ideal());
+        assert_eq!(direction.unitize(), direction);
+    }
+
+    #[test]
+    fn ideal_lines_and_planes() {
+        assert!(Line::new(0.0, 0.0, 0.0, 1.0, 0.0, 0.0).is_ideal());
+        assert!(!Line::X_AXIS.is_ideal());
+        assert!(Plane::new(0.0, 0.0, 0.0, 1.0).is_ideal());
+        assert!(!Plane::LEFT.is_ideal());
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn planes_cast_to_bytes_and_back() {
+        let planes = [Plane::new(1.0, 2.0, 3.0, 4.0), Plane::FORWARD];
+        let bytes: &[u8] = bytemuck::cast_slice(&planes);
+        assert_eq!(bytes.len(), 2 * 4 * std::mem::size_of::<f32>());
+        let restored: &[Plane] = bytemuck::cast_slice(bytes);
+        assert_eq!(restored, &planes);
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn nearly_equal_planes_are_relative_eq() {
+        let plane = Plane::new(1000.0, 0.0, 0.0, -5000.0);
+        let nudged = Plane::new(1000.00006, 0.0, 0.0, -5000.0);
+        approx::assert_relative_eq!(plane, nudged);
+        approx::assert_abs_diff_eq!(plane, nudged, epsilon = 1e-3);
+        approx::assert_relative_ne!(plane, Plane::new(1001.0, 0.0, 0.0, -5000.0));
+    }
+
+    #[test]
+    fn bitwise_identical_points_hash_together() {
+        use std::collections::HashSet;
+        let mut set = HashSet::new();
+        set.insert(Bits(Point3::new(1.0, 2.0, 3.0)));
+        set.insert(Bits(Point3::new(1.0, 2.0, 3.0)));
+        assert_eq!(set.len(), 1);
+        set.insert(Bits(Point3::new(1.0, 2.0, 3.0 + f32::EPSILON * 4.0)));
+        assert_eq!(set.len(), 2);
+        assert_ne!(
+            Bits(Point3::new(0.0, 0.0, 0.0)),
+            Bits(Point3::new(-0.0, 0.0, 0.0))
+        );
+    }
+
+    #[test]
+    fn blades_skip_zero_components() {
+        let blades: Vec<_> = Line::X_AXIS.blades().collect();
+        assert_eq!(blades, vec![("e41", 1.0)]);
+
+        let blades: Vec<_> = Motor::from_translation(Vec3::new(0.0, 2.0, 0.0))
+            .blades()
+            .collect();
+        assert_eq!(blades, vec![("scalar", 1.0), ("e31", 1.0)]);
+        assert_eq!(Plane::<f32>::default().blades().count(), 0);
+    }
+
+    #[test]
+    fn join_with_plane_direction_uses_the_normal() {
+        // PLANE_PERP_THROUGH_LINE: p1 = L0 ^ !p0.dir
+        let line: Line = Point3::new(1.0, 0.0, 0.0) ^ Point3::new(0.0, 1.0, 0.0);
+        let plane = Plane::new(1.0, 0.0, 1.0, 1.0);
+        let perpendicular: Plane = line ^ plane.direction();
+        assert_eq!(perpendicular, line ^ !plane.direction());
+        assert_approx_eq!(perpendicular.angle_to(&plane), std::f32::consts::FRAC_PI_2);
+
+        // LINE_PERP_THROUGH_POINT: L0 = P0 ^ !p0.dir
+        let point = Point3::new(1.0, 2.0, 3.0);
+        let normal: Line = point ^ plane.direction();
+        assert_eq!(normal, point ^ !plane.direction());
+        assert_approx_eq!(point.distance_to_line(&normal), 0.0);
+        assert_approx_eq!(
+            normal.angle_to(&(plane & Plane::UP)),
+            std::f32::consts::FRAC_PI_2
+        );
+    }
+
+    #[test]
+    fn axes_commute_to_the_third_axis() {
+        assert_approx_eq!(Line::X_AXIS.commutator(&Line::Y_AXIS), Line::Z_AXIS);
+        assert_approx_eq!(Line::Y_AXIS.commutator(&Line::Z_AXIS), Line::X_AXIS);
+        assert_approx_eq!(Line::Z_AXIS.commutator(&Line::X_AXIS), Line::Y_AXIS);
+
+        let line: Line = Point3::new(1.0, 2.0, 3.0) ^ Point3::new(-1.0, 0.5, 2.0);
+        assert!(line.commutator(&line).is_zero());
+    }
+
+    #[test]
+    fn inner_product_measures_the_metric() {
+        assert_approx_eq!(Plane::LEFT | Plane::LEFT, 1.0);
+        assert_approx_eq!(Plane::LEFT | Plane::UP, 0.0);
+        assert_approx_eq!(Line::X_AXIS | Line::X_AXIS, -1.0);
+        assert_approx_eq!(Line::X_AXIS | Line::Y_AXIS, 0.0);
+
+        let point = Point3::new(1.0, 2.0, 3.0);
+        let normal: Line = Plane::UP | point;
+        assert_approx_eq!(point.distance_to_line(&normal), 0.0);
+        // The line runs along the plane's normal, pointing back toward the plane.
+        assert_approx_eq!(normal.angle_to(&Line::Y_AXIS), std::f32::consts::PI);
+    }
+
+    #[test]
+    fn scaled_lines_are_projectively_equal() {
+        let line: Line = Point3::new(1.0, 2.0, 3.0) ^ Point3::new(-1.0, 0.5, 2.0);
+        let scaled = Line::new(
+            2.0 * line.vx,
+            2.0 * line.vy,
+            2.0 * line.vz,
+            2.0 * line.mx,
+            2.0 * line.my,
+            2.0 * line.mz,
+        );
+        assert!(!line.approx_eq(&scaled));
+        assert!(line.projective_approx_eq(&scaled, 1e-6));
+
+        assert!(!line.projective_approx_eq(&-scaled, 1e-6));
+        assert!(line.projective_approx_eq_unsigned(&-scaled, 1e-6));
+
+        assert!(!line.projective_approx_eq(&Line::X_AXIS, 1e-6));
+        assert!(!line.projective_approx_eq(&Line::default(), 1e-6));
+    }
+
+    #[test]
+    fn glam_conversions_round_trip() {
+        let plane = Plane::new(1.0, -2.0, 3.0, -4.0);
+        assert_eq!(Plane::from(Vec4::from(plane)), plane);
+
+        let point = Point3::new(1.0, 2.0, 3.0);
+        assert_eq!(Point3::from(Vec3::from(point)), point);
+
+        let v = Vec3::new(0.5, -1.5, 2.5);
+        assert_eq!(Vec3::from(Direction::from(v)), v);
+        assert_eq!(Vec3::from(LineDirection::from(v)), v);
+        assert_eq!(Vec3::from(PlaneDirection::from(v)), v);
+    }
+
+    #[test]
+    fn support_is_the_point_nearest_the_origin() {
+        assert_approx_eq!(Line::X_AXIS.support(), Point3::new(0.0, 0.0, 0.0));
+        let line =
+            Line::from_point_direction(Point3::new(3.0, 1.0, 0.0), Direction::new(2.0, 0.0, 0.0));
+        assert_approx_eq!(line.support(), Point3::new(0.0, 1.0, 0.0));
+
+        assert_approx_eq!(
+            Plane::new(1.0, 0.0, 0.0, -5.0).support(),
+            Point3::new(5.0, 0.0, 0.0)
+        );
+        assert_approx_eq!(
+            Plane::new(0.0, 2.0, 0.0, 4.0).support(),
+            Point3::new(0.0, -2.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn direction_is_the_weight() {
+        assert_eq!(Plane::LEFT.direction(), PlaneDirection::new(1.0, 0.0, 0.0));
+        let plane = Plane::new(0.0, 2.0, 0.0, 3.0);
+        assert_eq!(plane.direction(), plane.weight());
+
+        let line =
+            Line::from_point_direction(Point3::new(1.0, 2.0, 3.0), Direction::new(0.0, 0.0, 1.0));
+        assert_eq!(line.direction(), LineDirection::new(0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn almost_collinear_points_are_degenerate_with_a_tolerance() {
+        let a = Point3::new(0.0, 0.0, 0.0);
+        let b = Point3::new(1.0, 0.0, 0.0);
+        let c = Point3::new(2.0, 5e-4, 0.0);
+        let plane: Plane = a ^ b ^ c;
+        assert!(!plane.is_zero());
+        assert!(plane.is_zero_eps(1e-3));
+        assert_eq!(plane.try_normalize(1e-3), None);
+
+        let plane: Plane = a ^ b ^ Point3::new(2.0, 1.0, 0.0);
+        assert_approx_eq!(plane.try_normalize(1e-3).unwrap().norm(), 1.0);
+    }
+
+    #[test]
+    fn is_zero_is_is_zero_eps_with_the_default_threshold() {
+        let default = f32::EPSILON.sqrt();
+        for norm in [0.0, 1e-7, 1e-4, 3e-4, default, 4e-4, 1e-3, 1.0] {
+            let direction = Direction::new(norm, 0.0, 0.0);
+            assert_eq!(direction.is_zero(), direction.is_zero_eps(default));
+        }
+        let (small, large): (Direction, Direction) = (
+            Direction::new(1e-4, 0.0, 0.0),
+            Direction::new(1e-3, 0.0, 0.0),
+        );
+        assert!(small.is_zero());
+        assert!(!large.is_zero());
+    }
+
+    #[test]
+    fn batched_transform_matches_single_transforms() {
+        let motor = Motor::from_rotation(Line::Z_AXIS, 0.7)
+            .compose(&Motor::from_translation(Vec3::new(1.0, -2.0, 0.5)));
+        let points = [
+            Point3::new(1.0, 2.0, 3.0),
+            Point3::new(-1.0, 0.0, 0.5),
+            Point3::new(0.0, 0.0, 0.0),
+        ];
+        let mut moved = points;
+        motor.transform_points(&mut moved);
+        for (point, moved) in points.iter().zip(&moved) {
+            assert_eq!(*moved, motor.transform(point));
+        }
+
+        let planes = [Plane::LEFT, Plane::new(0.0, 1.0, 1.0, -2.0)];
+        let mut moved = planes;
+        motor.transform_planes(&mut moved);
+        for (plane, moved) in planes.iter().zip(&moved) {
+            assert_eq!(*moved, motor.transform(plane));
+        }
+    }
+
+    #[test]
+    fn line_plane_intersection_is_classified() {
+        let line: Line = Point3::new(0.0, 0.0, 0.0) ^ Point3::new(1.0, 0.0, 0.0);
+
+        let crossing = Plane::new(1.0, 0.0, 0.0, -2.0);
+        assert_eq!(
+            line.intersect_plane(&crossing),
+            LinePlaneIntersection::Point(Point3::new(2.0, 0.0, 0.0))
+        );
+
+        let parallel = Plane::new(0.0, 0.0, 1.0, -0.5);
+        match line.intersect_plane(&parallel) {
+            LinePlaneIntersection::Parallel(direction) => {
+                assert_approx_eq!(direction, Direction::new(-0.5, 0.0, 0.0))
+            }
+            other => panic!("expected a parallel line, got {other:?}"),
+        }
+
+        let containing = Plane::new(0.0, 0.0, 1.0, 0.0);
+        assert_eq!(
+            line.intersect_plane(&containing),
+            LinePlaneIntersection::Coincident
+        );
+    }
+
+    #[test]
+    fn translation_between_points() {
+        let a = Point3::new(1.0, 2.0, 3.0);
+        let b = Point3::new(-2.0, 0.5, 4.0);
+        let motor = Motor::translation_between(a, b);
+        assert_approx_eq!(motor.transform(&a), b);
+        assert_eq!(
+            (motor.rx, motor.ry, motor.rz, motor.rw),
+            (0.0, 0.0, 0.0, 1.0)
+        );
+        assert_eq!(motor.uw, 0.0);
+    }
+
+    #[test]
+    fn reflect_through_a_line_is_a_half_turn() {
+        let point = Point3::new(1.0, 0.0, 0.0);
+        assert_approx_eq!(Line::Z_AXIS.reflect(&point), Point3::new(-1.0, 0.0, 0.0));
+
+        let line: Line = Point3::new(0.0, 1.0, 0.0) ^ Point3::new(0.0, 1.0, 2.0);
+        assert_approx_eq!(
+            line.reflect(&Point3::new(1.0, 3.0, 5.0)),
+            Point3::new(-1.0, -1.0, 5.0)
+        );
+        let on_line = Point3::new(0.0, 1.0, 7.0);
+        assert_approx_eq!(line.reflect(&on_line), on_line);
+    }
+
+    #[test]
+    fn complements_swap_points_and_planes() {
+        let point = Point4::new(1.0, 2.0, 3.0, 1.0);
+        let plane = Plane::new(4.0, -5.0, 6.0, -7.0);
+        let line: Line = Point3::new(1.0, 2.0, 3.0) ^ Point3::new(-1.0, 0.5, 2.0);
+
+        assert_eq!(
+            Plane::LEFT.right_complement(),
+            Point4::new(-1.0, 0.0, 0.0, 0.0)
+        );
+        assert_eq!(
+            Point3::new(1.0, 2.0, 3.0).right_complement(),
+            Plane::new(1.0, 2.0, 3.0, 1.0)
+        );
+        assert_eq!(
+            !Direction::new(1.0, 2.0, 3.0),
+            PlaneDirection::new(1.0, 2.0, 3.0)
+        );
+
+        assert_eq!(point.right_complement().right_complement(), -point);
+        assert_eq!(plane.right_complement().right_complement(), -plane);
+        assert_eq!(line.right_complement().right_complement(), line);
+
+        assert_eq!(point.right_complement().left_complement(), point);
+        assert_eq!(plane.left_complement().right_complement(), plane);
+        assert_eq!(line.left_complement().right_complement(), line);
+    }
+
+    #[test]
+    fn tolerances_can_be_loosened() {
+        let line = Line::new(1e-3, 0.0, 0.0, 0.0, 1e-3, 0.0);
+        assert!(!line.is_zero());
+        assert!(line.is_zero_eps(1e-2));
+        assert_eq!(line.normalize_eps(1e-2), Line::default());
+        assert_approx_eq!(line.normalize_eps(1e-4).norm(), 1.0);
+
+        assert!(!line.is_ideal());
+        assert!(line.is_ideal_eps(1e-2));
+    }
+
+    #[test]
+    fn spherical_and_cylindrical_coordinates_round_trip() {
+        use std::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+        let point = Point3::new(1.0, 1.0, 2.0_f32.sqrt());
+
+        let (radius, polar, azimuth) = point.to_spherical();
+        assert_approx_eq!(radius, 2.0);
+        assert_approx_eq!(polar, FRAC_PI_4);
+        assert_approx_eq!(azimuth, FRAC_PI_4);
+        assert_approx_eq!(Point3::from_spherical(radius, polar, azimuth), point, 1e-6);
+
+        let (radius, azimuth, height) = point.to_cylindrical();
+        assert_approx_eq!(radius, 2.0_f32.sqrt());
+        assert_approx_eq!(azimuth, FRAC_PI_4);
+        assert_approx_eq!(height, 2.0_f32.sqrt());
+        assert_approx_eq!(
+            Point3::from_cylindrical(radius, azimuth, height),
+            point,
+            1e-6
+        );
+
+        assert_approx_eq!(
+            Point3::from_spherical(3.0, FRAC_PI_2, FRAC_PI_2),
+            Point3::new(0.0, 3.0, 0.0),
+            1e-6
+        );
+        assert_eq!(Point3::new(0.0, 0.0, 0.0).to_spherical(), (0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn entities_round_trip_through_arrays() {
+        let point = Point3::from([1.0, 2.0, 3.0]);
+        assert_eq!(point, Point3::new(1.0, 2.0, 3.0));
+        assert_eq!(point.to_array(), [1.0, 2.0, 3.0]);
+
+        let direction = Direction::from([0.0, -1.0, 2.0]);
+        assert_eq!(direction, Direction::new(0.0, -1.0, 2.0));
+        assert_eq!(<[f32; 3]>::from(direction), [0.0, -1.0, 2.0]);
+
+        let point = Point4::from([1.0, 2.0, 3.0, 2.0]);
+        assert_eq!(point, Point4::new(1.0, 2.0, 3.0, 2.0));
+        assert_eq!(point.to_array(), [1.0, 2.0, 3.0, 2.0]);
+
+        let plane = Plane::from([1.0, 0.0, 0.0, -5.0]);
+        assert_eq!(plane, Plane::new(1.0, 0.0, 0.0, -5.0));
+        assert_eq!(<[f32; 4]>::from(plane), [1.0, 0.0, 0.0, -5.0]);
+
+        let line = Line::from([0.0, 0.0, 1.0, 1.0, 0.0, 0.0]);
+        assert_eq!(line, Line::from_plucker([0.0, 0.0, 1.0], [1.0, 0.0, 0.0]));
+        assert_eq!(line.to_array(), [0.0, 0.0, 1.0, 1.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn direction_cross_and_dot() {
+        let x = Direction::new(1.0, 0.0, 0.0);
+        let y = Direction::new(0.0, 1.0, 0.0);
+        assert_eq!(x.cross(&y), Direction::new(0.0, 0.0, 1.0));
+        assert_eq!(y.cross(&x), Direction::new(0.0, 0.0, -1.0));
+        assert_eq!(x.dot(&y), 0.0);
+
+        let a = Direction::new(1.0, 2.0, 3.0);
+        let b = Direction::new(-2.0, 0.5, 4.0);
+        let cross = Vec3::from(a).cross(Vec3::from(b));
+        assert_approx_eq!(Vec3::from(a.cross(&b)), cross);
+        assert_approx_eq!(a.dot(&b), Vec3::from(a).dot(Vec3::from(b)));
+    }
+
+    #[test]
+    fn midpoint_and_centroid() {
+        let midpoint = Point3::new(0.0, 0.0, 0.0).midpoint(&Point3::new(2.0, 0.0, 0.0));
+        assert_eq!(midpoint, Point3::new(1.0, 0.0, 0.0));
+
+        let axes = [
+            Point3::new(1.0, 0.0, 0.0),
+            Point3::new(0.0, 1.0, 0.0),
+            Point3::new(0.0, 0.0, 1.0),
+        ];
+        let third = 1.0 / 3.0;
+        assert_approx_eq!(
+            Point3::centroid(&axes).unwrap(),
+            Point3::new(third, third, third)
+        );
+        assert_eq!(Point3::centroid(&[]), None);
+    }
+
+    #[test]
+    fn plucker_coordinates() {
+        assert_eq!(
+            Line::X_AXIS.to_plucker(),
+            ([1.0, 0.0, 0.0], [0.0, 0.0, 0.0])
+        );
+        assert_eq!(Line::X_AXIS.plucker_constraint(), 0.0);
+
+        // The moment of a line through p along d is p × d
+        let line =
+            Line::from_point_direction(Point3::new(0.0, 1.0, 0.0), Direction::new(1.0, 0.0, 0.0));
+        assert_approx_eq!(line, Line::from_plucker([1.0, 0.0, 0.0], [0.0, 0.0, -1.0]));
+        assert_approx_eq!(line.plucker_constraint(), 0.0);
+        let (direction, moment) = line.to_plucker();
+        assert_eq!(Line::from_plucker(direction, moment), line);
+    }
+
+    #[test]
+    fn coplanar_lines_intersect() {
+        assert!(Line::X_AXIS.is_coplanar(&Line::Y_AXIS, 1e-6));
+        assert_eq!(
+            Line::X_AXIS.intersection(&Line::Y_AXIS),
+            Some(Point3::new(0.0, 0.0, 0.0))
+        );
+
+        let a: Line = Point3::new(1.0, 2.0, 3.0) ^ Point3::new(2.0, 2.0, 5.0);
+        let b: Line = Point3::new(1.0, 2.0, 3.0) ^ Point3::new(-1.0, 4.0, 0.0);
+        assert!(a.is_coplanar(&b, 1e-5));
+        assert_approx_eq!(
+            a.intersection(&b).unwrap(),
+            Point3::new(1.0, 2.0, 3.0),
+            1e-5
+        );
+
+        let skew =
+            Line::from_point_direction(Point3::new(0.0, 0.0, 1.0), Direction::new(0.0, 1.0, 0.0));
+        assert!(!Line::X_AXIS.is_coplanar(&skew, 1e-6));
+        assert_eq!(Line::X_AXIS.intersection(&skew), None);
+
+        let parallel =
+            Line::from_point_direction(Point3::new(0.0, 1.0, 0.0), Direction::new(1.0, 0.0, 0.0));
+        assert!(Line::X_AXIS.is_coplanar(&parallel, 1e-6));
+        assert_eq!(Line::X_AXIS.intersection(&parallel), None);
+    }
+
+    #[test]
+    fn homogeneous_points_project_by_their_weight() {
+        let mut point = Point4::new(2.0, 4.0, -6.0, 2.0);
+        assert_eq!(point.project(), Point3::new(1.0, 2.0, -3.0));
+
+        point.homogenize();
+        assert_eq!(point, Point4::new(1.0, 2.0, -3.0, 1.0));
+        assert_eq!(Point4::from_point3(Point3::new(1.0, 2.0, -3.0)), point);
+
+        let mut ideal = Point4::new(1.0, 0.0, 0.0, 0.0);
+        ideal.homogenize();
+        assert_eq!(ideal, Point4::new(1.0, 0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn only_finite_points_project() {
+        let point = Point4::new(2.0, 4.0, -6.0, 2.0);
+        assert_eq!(point.try_project(), Some(Point3::new(1.0, 2.0, -3.0)));
+        assert_eq!(Point4::new(1.0, 0.0, 0.0, 0.0).try_project(), None);
+    }
+
+    #[test]
+    fn look_at_points_the_forward_axis_at_the_target() {
+        let from = Point3::new(1.0, 2.0, 3.0);
+        let target = Point3::new(-2.0, 0.0, 5.0);
+        let motor = Motor::look_at(from, target, Direction::new(0.0, 1.0, 0.0));
+        let expected = (Vec3::from(target) - Vec3::from(from)).normalize();
+        let forward = motor.transform(&Direction::new(0.0, 0.0, -1.0));
+        assert_approx_eq!(Vec3::from(forward), expected, 1e-5);
+        assert_approx_eq!(motor.transform(&Point3::new(0.0, 0.0, 0.0)), from, 1e-5);
+        let up = Vec3::from(motor.transform(&Direction::new(0.0, 1.0, 0.0)));
+        assert_approx_eq!(up.dot(expected), 0.0, 1e-5);
+        assert!(up.y > 0.0);
+
+        // Looking straight up still gives a rigid motion aiming at the target
+        let above = Point3::new(1.0, 7.0, 3.0);
+        let motor = Motor::look_at(from, above, Direction::new(0.0, 1.0, 0.0));
+        let forward = motor.transform(&Direction::new(0.0, 0.0, -1.0));
+        assert_approx_eq!(Vec3::from(forward), Vec3::Y, 1e-5);
+
+        let motor = Motor::look_at(from, from, Direction::new(0.0, 1.0, 0.0));
+        assert_approx_eq!(motor, Motor::from_translation(Vec3::from(from)));
+    }
+
+    #[test]
+    fn rotor_matches_glam_quaternion() {
+        let angle = std::f32::consts::FRAC_PI_2;
+        let rotor = Rotor::from_axis_angle(Line::Z_AXIS, angle);
+        let quat = Quat::from_rotation_z(angle);
+        assert!(rotor.to_quat().abs_diff_eq(quat, 1e-6));
+        assert_eq!(
+            Rotor::from(quat),
+            Rotor::new(quat.x, quat.y, quat.z, quat.w)
+        );
+
+        let point = Point3::new(1.0, 2.0, 3.0);
+        let expected = Point3::from(quat * Vec3::from(point));
+        assert_approx_eq!(rotor.transform(&point), expected);
+        assert_approx_eq!(Motor::from(rotor).transform(&point), expected);
+
+        let scaled = Rotor::new(0.0, 0.0, 2.0, 2.0);
+        assert_approx_eq!(scaled.normalize().norm(), 1.0);
+    }
+
+    #[test]
+    fn translators_compose_by_adding_offsets() {
+        let a = Translator::new(Vec3::new(1.0, 2.0, 3.0));
+        let b = Translator::new(Vec3::new(-4.0, 0.5, 1.0));
+        let ab = a.compose(&b);
+        assert_eq!(ab, Translator::new(Vec3::new(-3.0, 2.5, 4.0)));
+        assert_approx_eq!(Motor::from(a).compose(&Motor::from(b)), Motor::from(ab));
+
+        let point = Point3::new(1.0, 1.0, 1.0);
+        assert_approx_eq!(ab.transform(&point), Point3::new(-2.0, 3.5, 5.0));
+        assert_approx_eq!(ab.transform(&point), b.transform(&a.transform(&point)));
+
+        let direction = Direction::new(1.0, -2.0, 0.5);
+        assert_approx_eq!(a.transform(&direction), direction);
+    }
+
+    #[test]
+    fn containment() {
+        let origin = Point3::new(0.0, 0.0, 0.0);
+        assert!(Line::X_AXIS.contains_point(&origin, 1e-6));
+        assert!(!Line::X_AXIS.contains_point(&Point3::new(0.0, 1.0, 0.0), 1e-6));
+
+        let plane = Plane::new(1.0, 0.0, 0.0, 0.0);
+        // The plane x = 0 holds every point with x = 0
+        assert!(plane.contains_point(&Point3::new(0.0, 1.0, 0.0), 1e-6));
+        assert!(plane.contains_point(&Point3::new(0.0, 5.0, 7.0), 1e-6));
+        assert!(!plane.contains_point(&Point3::new(1.0, 5.0, 7.0), 1e-6));
+
+        assert!(plane.contains_line(&Line::Y_AXIS, 1e-6));
+        let in_plane: Line = Point3::new(0.0, 1.0, 2.0) ^ Point3::new(0.0, -3.0, 4.0);
+        assert!(plane.contains_line(&in_plane, 1e-6));
+        assert!(!plane.contains_line(&Line::X_AXIS, 1e-6));
+        let parallel =
+            Line::from_point_direction(Point3::new(1.0, 0.0, 0.0), Direction::new(0.0, 1.0, 0.0));
+        assert!(!plane.contains_line(&parallel, 1e-6));
+    }
+
+    #[test]
+    fn foot_and_mirror_across_a_plane() {
+        let plane = Plane::new(1.0, 0.0, 0.0, 0.0);
+        let point = Point3::new(3.0, 0.0, 0.0);
+        assert_approx_eq!(plane.foot(&point), Point3::new(0.0, 0.0, 0.0));
+        assert_approx_eq!(plane.mirror_point(&point), Point3::new(-3.0, 0.0, 0.0));
+
+        let plane = Plane::new(0.0, 2.0, 0.0, -2.0);
+        let point = Point3::new(1.0, 4.0, 2.0);
+        assert_approx_eq!(plane.foot(&point), Point3::new(1.0, 1.0, 2.0));
+        assert_approx_eq!(plane.mirror_point(&point), Point3::new(1.0, -2.0, 2.0));
+        assert_approx_eq!(plane.mirror_point(&point), plane.unitize().reflect(&point));
+    }
 }
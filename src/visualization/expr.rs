@@ -0,0 +1,179 @@
+use crate::pgai::{BulkWeight, Direction, Line, LineMoment, Motor, Plane, Point3, Transformable};
+use serde::{Deserialize, Serialize};
+
+/// One step in the construction of a scene. Steps are evaluated in order and every result is
+/// cached, so operands refer to earlier steps by their index in the list and an intermediate
+/// entity is computed once however often it is used.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Expr {
+    /// Input point `i` of the scene
+    Point(usize),
+    /// Input direction `i` of the scene
+    Direction(usize),
+    /// Input plane `i` of the scene
+    Plane(usize),
+    /// Input scalar `i` of the scene, such as an angle
+    Scalar(usize),
+    /// The join `a ^ b` of two earlier steps
+    Join(usize, usize),
+    /// The meet `a & b` of two earlier steps
+    Meet(usize, usize),
+    /// The ideal element perpendicular to a plane or line, `!x.direction()`: the normal
+    /// direction of a plane, the moment of a line through the origin
+    Orthogonal(usize),
+    /// The mirror image of step `b` across the plane of step `a`
+    Reflect(usize, usize),
+    /// Step `b` rotated about the line of step `a` by the angle of step `c`, in radians
+    Rotate(usize, usize, usize),
+    /// Draws the result of an earlier step in the next free slot of its kind, after the inputs
+    Show(usize),
+}
+
+/// An editable input of a scene, as read by `Expr::Point`, `Expr::Direction`, `Expr::Plane` and
+/// `Expr::Scalar`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Input {
+    Point(usize),
+    Direction(usize),
+    Plane(usize),
+    Scalar(usize),
+}
+
+/// The result of a step
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Value {
+    Point(Point3),
+    Direction(Direction),
+    Line(Line),
+    Plane(Plane),
+    LineMoment(LineMoment),
+    Scalar(f32),
+}
+
+/// The entities the inputs of a scene are read from
+pub struct Inputs<'a> {
+    pub points: &'a [Point3],
+    pub directions: &'a [Direction],
+    pub planes: &'a [Plane],
+    pub scalars: &'a [f32],
+}
+
+/// Evaluates the steps in order and returns the result of every step.
+pub fn evaluate(expressions: &[Expr], inputs: Inputs) -> Result<Vec<Value>, String> {
+    let mut values: Vec<Value> = Vec::with_capacity(expressions.len());
+    for (step, expr) in expressions.iter().enumerate() {
+        let operand = |index: usize| {
+            values
+                .get(index)
+                .copied()
+                .ok_or_else(|| format!("Step {step} refers to step {index}, which comes later"))
+        };
+        let value = match *expr {
+            Expr::Point(i) => inputs.points.get(i).copied().map(Value::Point),
+            Expr::Direction(i) => inputs.directions.get(i).copied().map(Value::Direction),
+            Expr::Plane(i) => inputs.planes.get(i).copied().map(Value::Plane),
+            Expr::Scalar(i) => inputs.scalars.get(i).copied().map(Value::Scalar),
+            Expr::Join(a, b) => join(operand(a)?, operand(b)?),
+            Expr::Meet(a, b) => meet(operand(a)?, operand(b)?),
+            Expr::Orthogonal(a) => orthogonal(operand(a)?),
+            Expr::Reflect(a, b) => reflect(operand(a)?, operand(b)?),
+            Expr::Rotate(a, b, c) => rotate(operand(a)?, operand(b)?, operand(c)?),
+            // A line moment is the ideal line of a plane through the origin, which is not drawn,
+            // and neither is a number
+            Expr::Show(a) => {
+                Some(operand(a)?).filter(|v| !matches!(v, Value::LineMoment(_) | Value::Scalar(_)))
+            }
+        };
+        values.push(value.ok_or_else(|| format!("Step {step} ({expr:?}) has no result"))?);
+    }
+    Ok(values)
+}
+
+/// For every step, whether it reads one of the `changed` inputs, directly or through earlier
+/// steps.
+pub fn dirty_steps(expressions: &[Expr], changed: &[Input]) -> Vec<bool> {
+    let mut dirty: Vec<bool> = Vec::with_capacity(expressions.len());
+    for expr in expressions {
+        // A step referring forward fails to evaluate anyway, count it as dirty
+        let operand = |index: usize| dirty.get(index).copied().unwrap_or(true);
+        let is_dirty = match *expr {
+            Expr::Point(i) => changed.contains(&Input::Point(i)),
+            Expr::Direction(i) => changed.contains(&Input::Direction(i)),
+            Expr::Plane(i) => changed.contains(&Input::Plane(i)),
+            Expr::Scalar(i) => changed.contains(&Input::Scalar(i)),
+            Expr::Join(a, b) | Expr::Meet(a, b) | Expr::Reflect(a, b) => operand(a) || operand(b),
+            Expr::Rotate(a, b, c) => operand(a) || operand(b) || operand(c),
+            Expr::Orthogonal(a) | Expr::Show(a) => operand(a),
+        };
+        dirty.push(is_dirty);
+    }
+    dirty
+}
+
+fn join(a: Value, b: Value) -> Option<Value> {
+    let value = match (a, b) {
+        (Value::Point(p), Value::Point(q)) => Value::Line(p ^ q),
+        (Value::Point(p), Value::Direction(d)) => Value::Line(p ^ d),
+        (Value::Direction(d), Value::Point(p)) => Value::Line(d ^ p),
+        (Value::Line(l), Value::Point(p)) => Value::Plane(l ^ p),
+        (Value::Line(l), Value::Direction(d)) => Value::Plane(l ^ d),
+        (Value::Point(p), Value::LineMoment(m)) => Value::Plane(p ^ m),
+        _ => return None,
+    };
+    Some(value)
+}
+
+fn meet(a: Value, b: Value) -> Option<Value> {
+    let value = match (a, b) {
+        (Value::Plane(p), Value::Plane(q)) => Value::Line(p & q),
+        (Value::Line(l), Value::Plane(p)) => Value::Point(Point3::from(l & p)),
+        (Value::Plane(p), Value::Line(l)) => Value::Point(Point3::from(p & l)),
+        _ => return None,
+    };
+    Some(value)
+}
+
+fn orthogonal(a: Value) -> Option<Value> {
+    match a {
+        Value::Plane(p) => Some(Value::Direction(!p.direction())),
+        Value::Line(l) => Some(Value::LineMoment(!l.direction())),
+        _ => None,
+    }
+}
+
+fn reflect(mirror: Value, x: Value) -> Option<Value> {
+    let Value::Plane(mirror) = mirror else {
+        return None;
+    };
+    // The sandwich scales by the squared weight, which the horizon does not have
+    if mirror.is_ideal() {
+        return None;
+    }
+    let mirror = mirror.unitize();
+    let value = match x {
+        Value::Point(p) => Value::Point(mirror.reflect(&p)),
+        Value::Direction(d) => Value::Direction(mirror.reflect(&d)),
+        Value::Line(l) => Value::Line(mirror.reflect(&l)),
+        Value::Plane(p) => Value::Plane(mirror.reflect(&p)),
+        _ => return None,
+    };
+    Some(value)
+}
+
+fn rotate(axis: Value, x: Value, angle: Value) -> Option<Value> {
+    let (Value::Line(axis), Value::Scalar(angle)) = (axis, angle) else {
+        return None;
+    };
+    if axis.is_ideal() {
+        return None;
+    }
+    let motor = Motor::from_rotation(axis, angle);
+    let value = match x {
+        Value::Point(p) => Value::Point(p.transform_by(&motor)),
+        Value::Direction(d) => Value::Direction(d.transform_by(&motor)),
+        Value::Line(l) => Value::Line(l.transform_by(&motor)),
+        Value::Plane(p) => Value::Plane(p.transform_by(&motor)),
+        _ => return None,
+    };
+    Some(value)
+}
@@ -4,20 +4,28 @@ use bevy::{
     render::{
         mesh::{Indices, PrimitiveTopology},
         render_asset::RenderAssetUsages,
+        view::screenshot::{Screenshot, ScreenshotCaptured, save_to_disk},
     },
 };
 use bevy_egui::{EguiContexts, EguiPlugin, EguiPrimaryContextPass, egui};
+use serde::{Deserialize, Serialize};
 use smooth_bevy_cameras::{
-    LookTransformPlugin,
+    LookTransform, LookTransformPlugin,
     controllers::orbit::{
         ControlEvent, OrbitCameraBundle, OrbitCameraController, OrbitCameraPlugin,
     },
 };
 
+mod expr;
 mod scenes;
 
-use crate::pgai::{BulkWeight, Direction, GeometricEntity, Line, Plane, Point3};
-use crate::visualization::scenes::PGAScene;
+use crate::pgai::{BulkWeight, Direction, Line, Motor, Plane, Point3, Point4};
+pub use crate::visualization::expr::{Expr, Input, Value};
+pub use crate::visualization::scenes::PGAScene;
+
+/// Weight norm below which an entity is drawn as ideal. Inputs go through several products before
+/// they are drawn, so this is looser than `f32::EPSILON`.
+const ZERO_TOLERANCE: f32 = 1e-5;
 
 #[derive(Default, Resource)]
 pub struct ObjectPool {
@@ -27,10 +35,71 @@ pub struct ObjectPool {
     pub directions: Vec<Entity>,
 }
 
+impl ObjectPool {
+    /// Spawns objects until each pool holds at least `min_size` of them and as many as `scene`
+    /// draws. New objects start hidden. Pools never shrink, surplus objects are hidden instead.
+    fn grow(
+        &mut self,
+        commands: &mut Commands,
+        meshes: &mut ResMut<Assets<Mesh>>,
+        scene_materials: &SceneMaterials,
+        scene: &PGAScene,
+        min_size: usize,
+    ) {
+        for i in self.points.len()..min_size.max(scene.points.len()) {
+            let color = scene.point_color(i);
+            self.points
+                .push(spawn_object(commands, color, format!("P{}", i)));
+        }
+        for i in self.lines.len()..min_size.max(scene.lines.len()) {
+            let color = scene.line_color(i);
+            self.lines
+                .push(spawn_object(commands, color, format!("L{}", i)));
+        }
+        for i in self.directions.len()..min_size.max(scene.directions.len()) {
+            let color = scene.direction_color(i);
+            self.directions
+                .push(spawn_object(commands, color, format!("D{}", i)));
+        }
+        for i in self.planes.len()..min_size.max(scene.planes.len()) {
+            let plane = scene
+                .planes
+                .get(i)
+                .copied()
+                .unwrap_or(Plane::new(1.0, 0.0, 0.0, 0.0));
+            let color = scene.plane_color(i);
+            self.planes.push(spawn_plane(
+                commands,
+                meshes,
+                scene_materials,
+                color,
+                plane,
+                i,
+            ));
+        }
+    }
+}
+
+/// Settings read when the visualization starts. Insert a customized one into the app returned by
+/// [`PGAVisualizationApp::new`] before running it.
+#[derive(Resource, Clone, Copy)]
+pub struct VisualizationConfig {
+    /// Number of objects of each kind spawned up front. Scenes drawing more grow the pool.
+    pub pool_size: usize,
+}
+
+impl Default for VisualizationConfig {
+    fn default() -> Self {
+        Self { pool_size: 10 }
+    }
+}
+
 #[derive(Default, Resource)]
 pub struct SceneSelector {
     pub scenes: Vec<PGAScene>,
     current_scene_index: usize,
+    /// The scenes as they were built, to reset edited inputs
+    defaults: Vec<PGAScene>,
 }
 
 #[derive(Default, Resource)]
@@ -45,6 +114,67 @@ pub struct SceneMaterials {
     pub orange: Handle<StandardMaterial>,
 }
 
+/// User toggles for the helpers drawn on top of the scene
+#[derive(Resource, Clone, Copy, PartialEq)]
+pub struct DisplaySettings {
+    pub show_labels: bool,
+    pub show_axes: bool,
+}
+
+/// Sizes of the gizmos drawn for the entities, adjustable in the "Gizmos" window
+#[derive(Resource, Clone, Copy, PartialEq)]
+pub struct GizmoStyle {
+    /// Radius of the spheres drawn at points
+    pub point_radius: f32,
+    /// How far the segment drawn for a line reaches on either side of its support point
+    pub line_length: f32,
+    /// Length of the arrow showing the orientation of a line
+    pub arrow_length: f32,
+    /// Length of the arrow drawn from the origin for a unit direction
+    pub direction_scale: f32,
+}
+
+/// Whether scenes with an animation are currently moving
+#[derive(Default, Resource)]
+pub struct AnimationPlayback {
+    pub playing: bool,
+}
+
+/// Screenshot options and the confirmation shown after a capture
+#[derive(Default, Resource)]
+pub struct ScreenshotSettings {
+    /// Whether the egui windows are part of the screenshot
+    pub include_ui: bool,
+    hide_ui_this_frame: bool,
+    confirmation: Option<(String, f32)>,
+}
+
+/// The inputs edited since the last rebuild, so that a scene that does not read them is not
+/// recomputed. Changes that are not tracked per input, such as loading or animating the inputs,
+/// mark everything.
+#[derive(Default, Resource)]
+pub struct DirtyInputs {
+    inputs: Vec<Input>,
+    all: bool,
+}
+
+impl DirtyInputs {
+    pub fn mark(&mut self, input: Input) {
+        self.inputs.push(input);
+    }
+
+    pub fn mark_all(&mut self) {
+        self.all = true;
+    }
+
+    /// The edited inputs, or `None` when everything has to be recomputed. Clears the marks.
+    pub fn take(&mut self) -> Option<Vec<Input>> {
+        let all = std::mem::take(&mut self.all);
+        let inputs = std::mem::take(&mut self.inputs);
+        (!all && !inputs.is_empty()).then_some(inputs)
+    }
+}
+
 #[derive(Event)]
 pub struct SceneChangedEvent;
 
@@ -79,8 +209,8 @@ struct LinkedLabel(Entity);
 #[derive(Component)]
 struct Label;
 
-#[derive(Component, Copy, Clone)]
-enum SceneColor {
+#[derive(Component, Copy, Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub enum SceneColor {
     YELLOW,
     RED,
     GREEN,
@@ -134,6 +264,51 @@ impl SceneSelector {
     pub fn len(&self) -> usize {
         self.scenes.len()
     }
+
+    /// Replaces all scenes and remembers them as the defaults to reset to
+    pub fn set_scenes(&mut self, scenes: Vec<PGAScene>) {
+        self.defaults = scenes.clone();
+        self.scenes = scenes;
+        self.current_scene_index = 0;
+    }
+
+    /// Restores the current scene to the state it was built with
+    pub fn reset_current(&mut self) {
+        if let Some(default) = self.defaults.get(self.current_scene_index) {
+            self.scenes[self.current_scene_index] = default.clone();
+        }
+    }
+
+    pub fn current_index(&self) -> usize {
+        self.current_scene_index
+    }
+
+    /// Selects the scene at `index`, ignoring indices past the last scene.
+    pub fn set_current_index(&mut self, index: usize) {
+        if index < self.len() {
+            self.current_scene_index = index;
+        }
+    }
+}
+
+impl Default for DisplaySettings {
+    fn default() -> Self {
+        Self {
+            show_labels: true,
+            show_axes: true,
+        }
+    }
+}
+
+impl Default for GizmoStyle {
+    fn default() -> Self {
+        Self {
+            point_radius: 0.01,
+            line_length: 4.0,
+            arrow_length: 0.5,
+            direction_scale: 2.0,
+        }
+    }
 }
 
 impl SceneColor {
@@ -176,13 +351,51 @@ fn spawn_object(commands: &mut Commands, color: SceneColor, text: String) -> Ent
         .id()
 }
 
+/// Gives the pooled objects and their labels the colors of the current scene
+fn update_colors(
+    scene_selector: Res<SceneSelector>,
+    object_pool: Res<ObjectPool>,
+    scene_materials: Res<SceneMaterials>,
+    mut objects: Query<(
+        &mut SceneColor,
+        &LinkedLabel,
+        Option<&mut MeshMaterial3d<StandardMaterial>>,
+    )>,
+    mut labels: Query<&mut TextColor, With<Label>>,
+) {
+    let scene = scene_selector.current();
+    let pools: [(&Vec<Entity>, fn(&PGAScene, usize) -> SceneColor); 4] = [
+        (&object_pool.points, PGAScene::point_color),
+        (&object_pool.lines, PGAScene::line_color),
+        (&object_pool.planes, PGAScene::plane_color),
+        (&object_pool.directions, PGAScene::direction_color),
+    ];
+    for (entities, color_of) in pools {
+        for (index, entity) in entities.iter().enumerate() {
+            let color = color_of(scene, index);
+            let Ok((mut scene_color, label, material)) = objects.get_mut(*entity) else {
+                continue;
+            };
+            *scene_color = color;
+            if let Some(mut material) = material {
+                material.0 = scene_materials.find(color);
+            }
+            if let Ok(mut text_color) = labels.get_mut(label.0) {
+                text_color.0 = color.linear_rgba().into();
+            }
+        }
+    }
+}
+
 fn plane_transform(plane: &Plane) -> Transform {
-    let plane = plane.unitize();
-    let distance = plane.w;
-    let normal = Vec3::from(plane.direction()).normalize();
+    // Ideal planes are drawn as gizmos, collapse their mesh
+    if plane.is_ideal_eps(ZERO_TOLERANCE) {
+        return Transform::from_scale(Vec3::ZERO);
+    }
+    let normal = plane.normal();
     let rotation = Quat::from_rotation_arc(Vec3::Y, normal);
     Transform {
-        translation: -normal * distance,
+        translation: normal * plane.distance(),
         rotation,
         ..Default::default()
     }
@@ -230,6 +443,7 @@ fn set_visibility<T>(
     object_query: &mut Query<(&mut Visibility, &LinkedLabel)>,
     entities: &Vec<Entity>,
     objects: &Vec<T>,
+    show_labels: bool,
 ) {
     for (index, entity) in entities.iter().enumerate() {
         let visibility = if index < objects.len() {
@@ -243,25 +457,49 @@ fn set_visibility<T>(
             .map(|(mut v, linked_label)| {
                 *v = visibility;
                 if let Ok(mut label_vis) = label_query.get_mut(linked_label.0) {
-                    *label_vis = visibility;
+                    *label_vis = if show_labels {
+                        visibility
+                    } else {
+                        Visibility::Hidden
+                    };
                 }
             })
             .ok();
     }
 }
 
+/// Spawns pooled objects for scenes that draw more entities than the pool holds
+fn grow_object_pool(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    scene_materials: Res<SceneMaterials>,
+    mut object_pool: ResMut<ObjectPool>,
+    scene_selector: Res<SceneSelector>,
+) {
+    object_pool.grow(
+        &mut commands,
+        &mut meshes,
+        scene_materials.as_ref(),
+        scene_selector.current(),
+        0,
+    );
+}
+
 fn update_visibility(
     mut label_query: Query<&mut Visibility, (With<Label>, Without<LinkedLabel>)>,
     mut object_query: Query<(&mut Visibility, &LinkedLabel)>,
     object_pool: ResMut<ObjectPool>,
     scene_selector: ResMut<SceneSelector>,
+    display_settings: Res<DisplaySettings>,
 ) {
     let scene = scene_selector.current();
+    let show_labels = display_settings.show_labels;
     set_visibility(
         &mut label_query,
         &mut object_query,
         &object_pool.points,
         &scene.points,
+        show_labels,
     );
 
     set_visibility(
@@ -269,6 +507,7 @@ fn update_visibility(
         &mut object_query,
         &object_pool.lines,
         &scene.lines,
+        show_labels,
     );
 
     set_visibility(
@@ -276,6 +515,7 @@ fn update_visibility(
         &mut object_query,
         &object_pool.planes,
         &scene.planes,
+        show_labels,
     );
 
     set_visibility(
@@ -283,6 +523,7 @@ fn update_visibility(
         &mut object_query,
         &object_pool.directions,
         &scene.directions,
+        show_labels,
     );
 }
 
@@ -309,14 +550,40 @@ impl PGAVisualizationApp {
         .add_event::<SceneChangedEvent>()
         .add_event::<InputChangedEvent>()
         .insert_resource(ObjectPool::default())
+        .insert_resource(VisualizationConfig::default())
+        .insert_resource(DirtyInputs::default())
         .insert_resource(SceneSelector::default())
         .insert_resource(SceneMaterials::default())
+        .insert_resource(DisplaySettings::default())
+        .insert_resource(GizmoStyle::default())
+        .insert_resource(AnimationPlayback::default())
+        .insert_resource(ScreenshotSettings::default())
         .insert_resource(ClearColor(Color::srgb(0.05, 0.05, 0.08))) // Very dark blue-gray
         .add_systems(Startup, (setup_scene, PGAScene::setup, setup_ui))
-        .add_systems(Update, (draw_pga_gizmos, input_map, scene_selection_input))
         .add_systems(
             Update,
-            (update_scene_ui, update_visibility).run_if(on_event::<SceneChangedEvent>),
+            (
+                draw_pga_gizmos,
+                input_map,
+                scene_selection_input,
+                animate_scene,
+                screenshot_input,
+                camera_preset_input,
+                frame_all_input,
+                pick_plane,
+            ),
+        )
+        .add_systems(
+            Update,
+            (update_scene_ui, update_colors).run_if(on_event::<SceneChangedEvent>),
+        )
+        .add_systems(
+            Update,
+            // The pool grows first so that the new objects are made visible in the same frame
+            (grow_object_pool, update_visibility)
+                .chain()
+                .after(PGAScene::rebuild)
+                .run_if(on_event::<SceneChangedEvent>.or(resource_changed::<DisplaySettings>)),
         )
         .add_systems(
             Update,
@@ -325,7 +592,18 @@ impl PGAVisualizationApp {
                 .run_if(on_event::<InputChangedEvent>.or(on_event::<SceneChangedEvent>)),
         )
         .add_systems(PostUpdate, update_label_positions)
-        .add_systems(EguiPrimaryContextPass, coordinate_editor_ui);
+        .add_systems(
+            EguiPrimaryContextPass,
+            (
+                coordinate_editor_ui,
+                scene_selector_ui,
+                gizmo_style_ui,
+                screenshot_confirmation_ui,
+            ),
+        );
+
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        app.add_systems(Update, crate::web::apply_web_commands);
 
         app
     }
@@ -343,6 +621,7 @@ fn setup_scene(
     mut scene_materials: ResMut<SceneMaterials>,
     mut object_pool: ResMut<ObjectPool>,
     mut meshes: ResMut<Assets<Mesh>>,
+    config: Res<VisualizationConfig>,
 ) {
     let mut create_material = |color: SceneColor| {
         materials.add(StandardMaterial {
@@ -370,32 +649,13 @@ fn setup_scene(
             Vec3::Y,
         ));
 
-    let max_objects = 10;
-    object_pool.points = (0..max_objects)
-        .map(|i| spawn_object(&mut commands, SceneColor::WHITE, format!("P{}", i)))
-        .collect();
-
-    object_pool.lines = (0..max_objects)
-        .map(|i| spawn_object(&mut commands, SceneColor::YELLOW, format!("L{}", i)))
-        .collect();
-
-    object_pool.directions = (0..max_objects)
-        .map(|i| spawn_object(&mut commands, SceneColor::ORANGE, format!("D{}", i)))
-        .collect();
-
-    object_pool.planes = (0..max_objects)
-        .map(|i| {
-            let plane = Plane::new(1.0, 0.0, 0.0, 0.0);
-            spawn_plane(
-                &mut commands,
-                &mut meshes,
-                scene_materials.as_ref(),
-                SceneColor::CYAN,
-                plane,
-                i,
-            )
-        })
-        .collect();
+    object_pool.grow(
+        &mut commands,
+        &mut meshes,
+        scene_materials.as_ref(),
+        &PGAScene::default(),
+        config.pool_size,
+    );
 }
 
 /// Setup UI elements
@@ -405,7 +665,9 @@ fn setup_ui(mut commands: Commands, windows: Query<&Window>) {
     };
     // Create UI text for scene name in top-left corner
     commands.spawn((
-        Text::new("Left Mouse Down to orbit. Scroll to zoom. Press arrows to change scene."),
+        Text::new(
+            "Left Mouse Down to orbit. Scroll to zoom. Arrows or the dropdown change the scene.",
+        ),
         Node {
             position_type: PositionType::Absolute,
             left: Val::Px(10.0),
@@ -414,6 +676,15 @@ fn setup_ui(mut commands: Commands, windows: Query<&Window>) {
         },
         SceneNameText,
     ));
+    commands.spawn((
+        Text::new("1 front, 2 side, 3 top, 0 isometric view, F frame all"),
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(10.0),
+            top: Val::Px(window.height() - 30.0),
+            ..default()
+        },
+    ));
 }
 
 /// Update the scene name text when scene changes
@@ -443,10 +714,311 @@ fn scene_selection_input(
     }
 }
 
+/// System showing a dropdown with all scenes, as an alternative to the arrow keys
+fn scene_selector_ui(
+    mut contexts: EguiContexts,
+    mut scene_selector: ResMut<SceneSelector>,
+    mut notify_scene_changed: EventWriter<SceneChangedEvent>,
+    screenshot: Res<ScreenshotSettings>,
+) {
+    if screenshot.hide_ui_this_frame {
+        return;
+    }
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+    let mut selected = scene_selector.current_index();
+    egui::Window::new("Scene")
+        .resizable(false)
+        .anchor(egui::Align2::RIGHT_TOP, [-10.0, 10.0])
+        .show(ctx, |ui| {
+            egui::ComboBox::from_id_salt("scene_selector")
+                .width(400.0)
+                .selected_text(scene_selector.current().name.as_str())
+                .show_ui(ui, |ui| {
+                    for (index, scene) in scene_selector.scenes.iter().enumerate() {
+                        ui.selectable_value(&mut selected, index, scene.name.as_str());
+                    }
+                });
+        });
+
+    if selected != scene_selector.current_index() {
+        scene_selector.set_current_index(selected);
+        notify_scene_changed.write(SceneChangedEvent);
+    }
+}
+
+/// System showing sliders for the gizmo sizes, collapsed until needed
+fn gizmo_style_ui(
+    mut contexts: EguiContexts,
+    mut style: ResMut<GizmoStyle>,
+    screenshot: Res<ScreenshotSettings>,
+) {
+    if screenshot.hide_ui_this_frame {
+        return;
+    }
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+    // Edit a copy so that change detection only fires when a slider moves
+    let mut edited = *style;
+    egui::Window::new("Gizmos")
+        .default_open(false)
+        .resizable(false)
+        .anchor(egui::Align2::LEFT_BOTTOM, [10.0, -10.0])
+        .show(ctx, |ui| {
+            ui.add(egui::Slider::new(&mut edited.point_radius, 0.005..=0.2).text("Point radius"));
+            ui.add(egui::Slider::new(&mut edited.line_length, 1.0..=20.0).text("Line length"));
+            ui.add(egui::Slider::new(&mut edited.arrow_length, 0.1..=2.0).text("Arrow length"));
+            ui.add(
+                egui::Slider::new(&mut edited.direction_scale, 0.5..=5.0).text("Direction scale"),
+            );
+        });
+    style.set_if_neq(edited);
+}
+
+/// System selecting the plane under the cursor on a left click and highlighting it in white. The
+/// ray through the cursor is a PGA line, which meets every plane in a point.
+fn pick_plane(
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    scene_selector: Res<SceneSelector>,
+    object_pool: Res<ObjectPool>,
+    scene_materials: Res<SceneMaterials>,
+    mut plane_materials: Query<(&mut MeshMaterial3d<StandardMaterial>, &SceneColor)>,
+    mut contexts: EguiContexts,
+) {
+    if !mouse_buttons.just_pressed(MouseButton::Left) {
+        return;
+    }
+    if let Ok(ctx) = contexts.ctx_mut() {
+        if ctx.is_pointer_over_area() {
+            return;
+        }
+    }
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    let Some(cursor) = window.cursor_position() else {
+        return;
+    };
+    let Ok((camera, camera_transform)) = camera_query.single() else {
+        return;
+    };
+    let Ok(ray) = camera.viewport_to_world(camera_transform, cursor) else {
+        return;
+    };
+
+    let origin = ray.origin;
+    let direction = *ray.direction;
+    let ray_line = Line::from_point_direction(
+        Point3::new(origin.x, origin.y, origin.z),
+        Direction::from(direction),
+    );
+    let planes = &scene_selector.current().planes;
+    let picked = planes
+        .iter()
+        .enumerate()
+        .filter_map(|(index, plane)| {
+            let hit: Point4 = *plane & ray_line;
+            // Rays parallel to the plane meet it at infinity
+            if hit.is_ideal_eps(ZERO_TOLERANCE) {
+                return None;
+            }
+            let distance = (Vec3::from(Point3::from(hit)) - origin).dot(direction);
+            (distance > 0.0).then_some((index, distance))
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(index, _)| index);
+
+    for (index, entity) in object_pool.planes.iter().enumerate() {
+        if let Ok((mut material, color)) = plane_materials.get_mut(*entity) {
+            material.0 = if picked == Some(index) {
+                scene_materials.white.clone()
+            } else {
+                scene_materials.find(*color)
+            };
+        }
+    }
+}
+
+/// Direction from the origin to the eye for the preset views on keys 1, 2, 3 and 0
+const CAMERA_PRESETS: [(KeyCode, Vec3); 4] = [
+    (KeyCode::Digit1, Vec3::Z),
+    (KeyCode::Digit2, Vec3::X),
+    // Looking straight down the up vector has no defined orientation, so tilt slightly
+    (KeyCode::Digit3, Vec3::new(0.0, 1.0, 1e-3)),
+    (KeyCode::Digit0, Vec3::ONE),
+];
+
+/// System moving the camera to a preset view of the origin, keeping its distance. The camera's
+/// smoother takes care of the transition.
+fn camera_preset_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut cameras: Query<&mut LookTransform, With<OrbitCameraController>>,
+    mut contexts: EguiContexts,
+) {
+    // Digits typed into the coordinate editor are not view changes
+    if let Ok(ctx) = contexts.ctx_mut() {
+        if ctx.wants_keyboard_input() {
+            return;
+        }
+    }
+    let Some(eye_direction) = CAMERA_PRESETS
+        .iter()
+        .find(|(key, _)| keyboard.just_pressed(*key))
+        .map(|(_, direction)| direction.normalize())
+    else {
+        return;
+    };
+    for mut look in cameras.iter_mut() {
+        let radius = look.radius();
+        look.target = Vec3::ZERO;
+        look.eye = eye_direction * radius;
+    }
+}
+
+/// Distance of the eye from a scene whose bounds are a single point
+const FRAME_SINGLE_POINT_DISTANCE: f32 = 5.0;
+
+/// System moving the camera to frame everything in the current scene when F is pressed,
+/// keeping the direction it looks from. Scenes without finite entities leave the camera alone.
+fn frame_all_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    scene_selector: Res<SceneSelector>,
+    mut cameras: Query<&mut LookTransform, With<OrbitCameraController>>,
+    mut contexts: EguiContexts,
+) {
+    if let Ok(ctx) = contexts.ctx_mut() {
+        if ctx.wants_keyboard_input() {
+            return;
+        }
+    }
+    if !keyboard.just_pressed(KeyCode::KeyF) {
+        return;
+    }
+    let Some((min, max)) = scene_selector.current().bounds() else {
+        return;
+    };
+    let center = (min + max) / 2.0;
+    let radius = (max - min).length() / 2.0;
+    // The bounding sphere fits the default vertical field of view of π/4
+    let distance = if radius < ZERO_TOLERANCE {
+        FRAME_SINGLE_POINT_DISTANCE
+    } else {
+        radius / (std::f32::consts::PI / 8.0).sin()
+    };
+    for mut look in cameras.iter_mut() {
+        let eye_direction = (look.eye - look.target).try_normalize().unwrap_or(Vec3::Z);
+        look.target = center;
+        look.eye = center + eye_direction * distance;
+    }
+}
+
+/// How long the confirmation of a screenshot stays on screen, in seconds
+const SCREENSHOT_CONFIRMATION_DURATION: f32 = 3.0;
+
+/// System saving a screenshot of the primary window to the working directory when F12 is pressed
+fn screenshot_input(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    #[cfg(target_arch = "wasm32")] time: Res<Time>,
+    mut settings: ResMut<ScreenshotSettings>,
+) {
+    settings.hide_ui_this_frame = false;
+    if !keyboard.just_pressed(KeyCode::F12) {
+        return;
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default();
+    // There is no system clock on the web, use the time since startup instead
+    #[cfg(target_arch = "wasm32")]
+    let timestamp = time.elapsed().as_millis();
+    let path = format!("pga_screenshot_{timestamp}.png");
+    // The capture is taken from this frame, so the UI systems skip drawing it
+    settings.hide_ui_this_frame = !settings.include_ui;
+    commands
+        .spawn(Screenshot::primary_window())
+        .observe(save_to_disk(path.clone()))
+        .observe(
+            move |_: Trigger<ScreenshotCaptured>,
+                  time: Res<Time>,
+                  mut settings: ResMut<ScreenshotSettings>| {
+                info!("Saved screenshot to {path}");
+                let until = time.elapsed_secs() + SCREENSHOT_CONFIRMATION_DURATION;
+                settings.confirmation = Some((format!("Saved {path}"), until));
+            },
+        );
+}
+
+/// System showing a short confirmation after a screenshot was taken
+fn screenshot_confirmation_ui(
+    mut contexts: EguiContexts,
+    time: Res<Time>,
+    settings: Res<ScreenshotSettings>,
+) {
+    let Some((message, until)) = &settings.confirmation else {
+        return;
+    };
+    if settings.hide_ui_this_frame || time.elapsed_secs() > *until {
+        return;
+    }
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+    egui::Area::new(egui::Id::new("screenshot_confirmation"))
+        .anchor(egui::Align2::CENTER_BOTTOM, [0.0, -40.0])
+        .show(ctx, |ui| {
+            ui.label(message);
+        });
+}
+
+/// System moving the inputs of an animated scene a little further every frame. Stopping keeps the
+/// inputs where they are, so the scene holds its last pose.
+fn animate_scene(
+    time: Res<Time>,
+    playback: Res<AnimationPlayback>,
+    mut scene_selector: ResMut<SceneSelector>,
+    mut notify_input_changed: EventWriter<InputChangedEvent>,
+    mut dirty_inputs: ResMut<DirtyInputs>,
+) {
+    if !playback.playing {
+        return;
+    }
+    let scene = scene_selector.current_mut();
+    let Some(animation) = scene.animation else {
+        return;
+    };
+
+    let step = Motor::IDENTITY.slerp(&animation, scene.angular_velocity * time.delta_secs());
+    for point in scene.points.iter_mut().take(scene.input_point_count) {
+        *point = step.transform(&*point);
+    }
+    for direction in scene
+        .directions
+        .iter_mut()
+        .take(scene.input_direction_count)
+    {
+        *direction = step.transform(&*direction);
+    }
+    for plane in scene.planes.iter_mut().take(scene.input_plane_count) {
+        *plane = step.transform(&*plane);
+    }
+    dirty_inputs.mark_all();
+    notify_input_changed.write(InputChangedEvent);
+}
+
 /// System to draw PGA objects using Bevy's gizmo API
 fn draw_pga_gizmos(
     mut gizmos: Gizmos,
     scene_selector: Res<SceneSelector>,
+    display_settings: Res<DisplaySettings>,
+    style: Res<GizmoStyle>,
     // points: Query<(&PointVisual, &SceneColor)>,
     // lines: Query<(&LineVisual, &SceneColor)>,
     // directions: Query<(&DirectionVisual, &SceneColor)>,
@@ -454,61 +1026,61 @@ fn draw_pga_gizmos(
 ) {
     let scene = scene_selector.current();
     // Draw coordinate axes
-    gizmos.line(Vec3::ZERO, Vec3::X * 2.0, LinearRgba::RED);
-    gizmos.line(Vec3::ZERO, Vec3::Y * 2.0, LinearRgba::GREEN);
-    gizmos.line(Vec3::ZERO, Vec3::Z * 2.0, LinearRgba::BLUE);
+    if display_settings.show_axes {
+        gizmos.line(Vec3::ZERO, Vec3::X * 2.0, LinearRgba::RED);
+        gizmos.line(Vec3::ZERO, Vec3::Y * 2.0, LinearRgba::GREEN);
+        gizmos.line(Vec3::ZERO, Vec3::Z * 2.0, LinearRgba::BLUE);
+    }
 
     // Draw points as small spheres
-    for point in &scene.points {
+    for (index, point) in scene.points.iter().enumerate() {
         let pos = Vec3::from(*point);
-        gizmos.sphere(pos, 0.01, SceneColor::WHITE.linear_rgba());
+        gizmos.sphere(
+            pos,
+            style.point_radius,
+            scene.point_color(index).linear_rgba(),
+        );
     }
 
     // Draw directions as arrows from origin
-    for &direction in &scene.directions {
+    for (index, &direction) in scene.directions.iter().enumerate() {
         let dir = Vec3::from(direction);
-        gizmos.arrow(Vec3::ZERO, dir * 2.0, SceneColor::ORANGE.linear_rgba());
+        gizmos.arrow(
+            Vec3::ZERO,
+            dir * style.direction_scale,
+            scene.direction_color(index).linear_rgba(),
+        );
     }
 
     // Draw lines
-    for line in &scene.lines {
-        draw_pga_line(&mut gizmos, line, SceneColor::YELLOW.linear_rgba());
+    for (index, line) in scene.lines.iter().enumerate() {
+        draw_pga_line(
+            &mut gizmos,
+            line,
+            scene.line_color(index).linear_rgba(),
+            &style,
+        );
     }
 
     // Draw plane normal arrows (planes themselves are drawn as meshes)
-    for plane in &scene.planes {
-        draw_plane_normal_arrow(&mut gizmos, plane, SceneColor::CYAN.linear_rgba());
+    for (index, plane) in scene.planes.iter().enumerate() {
+        draw_plane_normal_arrow(&mut gizmos, plane, scene.plane_color(index).linear_rgba());
     }
 }
 
 fn pga_point_on_plane(plane: &Plane) -> Vec3 {
-    let plane = plane.unitize();
-
-    if plane.w.abs() < f32::EPSILON {
-        // TODO: This should return None..., since the plane does not go through the origin.
-        Vec3::ZERO // Ideal plane, no finite point
-    } else {
-        let direction = Vec3::from(plane.direction());
-        direction * -plane.w
+    if plane.is_ideal_eps(ZERO_TOLERANCE) {
+        return Vec3::ZERO; // Ideal plane, no finite point
     }
+    Vec3::from(plane.support())
 }
 
 fn pga_point_on_line(line: &Line) -> Vec3 {
-    let direction = line.direction();
     // If direction is zero, this is an ideal line (line at infinity)
-    if direction.is_zero() {
+    if line.is_ideal_eps(ZERO_TOLERANCE) {
         return Vec3::ZERO; // Ideal line, return origin as placeholder
     }
-
-    let direction = Vec3::from(direction);
-    let moment = Vec3::from(line.bulk());
-    // Find a point on the line using the relationship: point = direction × moment / |direction|²
-    let dir_length_sq = direction.length_squared();
-    if dir_length_sq > f32::EPSILON {
-        direction.cross(moment) / dir_length_sq
-    } else {
-        Vec3::ZERO
-    }
+    Vec3::from(line.support())
 }
 
 /// Convert a PGA Direction to a Bevy Vec3
@@ -516,42 +1088,92 @@ fn pga_direction_to_vec3(direction: Direction) -> Vec3 {
     Vec3::from(direction)
 }
 
+/// Radius of the circles and arrows standing in for entities at infinity
+const IDEAL_RADIUS: f32 = 6.0;
+
 /// Draw a PGA line using gizmos
-fn draw_pga_line(gizmos: &mut Gizmos, line: &Line, color: LinearRgba) {
-    let direction = line.direction();
-    // If direction is zero, this is an ideal line (line at infinity)
-    if direction.is_zero() {
+fn draw_pga_line(gizmos: &mut Gizmos, line: &Line, color: LinearRgba, style: &GizmoStyle) {
+    // Lines at infinity have no direction
+    let Some(direction) = line.direction_unit_eps(ZERO_TOLERANCE) else {
+        draw_ideal_line(gizmos, line, color);
         return;
-    }
-    // Extract direction and moment components
+    };
     let direction = Vec3::from(direction);
-    let moment = Vec3::from(line.bulk());
+    let point_on_line = Vec3::from(line.support());
 
-    // Find a point on the line using the relationship: point = direction × moment / |direction|²
-    let dir_length_sq = direction.length_squared();
-    if dir_length_sq > f32::EPSILON {
-        let point_on_line = direction.cross(moment) / dir_length_sq;
+    // Draw line segment
+    let start = point_on_line - direction * style.line_length;
+    let end = point_on_line + direction * style.line_length;
 
-        // Draw line segment
-        let length = 4.0;
-        let start = point_on_line - direction.normalize() * length;
-        let end = point_on_line + direction.normalize() * length;
+    gizmos.line(start, end, color);
 
-        gizmos.line(start, end, color);
+    // Draw direction arrow at the point on the line
+    gizmos.arrow(
+        point_on_line,
+        point_on_line + direction * style.arrow_length,
+        color,
+    );
+}
 
-        // Draw direction arrow at the point on the line
-        gizmos.arrow(
-            point_on_line,
-            point_on_line + direction.normalize() * 0.5,
-            color,
-        );
+/// Draw a line at infinity as a faint circle far away, in the plane through the origin whose
+/// points at infinity it holds, which is perpendicular to the moment
+fn draw_ideal_line(gizmos: &mut Gizmos, line: &Line, color: LinearRgba) {
+    let moment = Vec3::from(line.bulk());
+    if moment.length() < ZERO_TOLERANCE {
+        return;
+    }
+    let rotation = Quat::from_rotation_arc(Vec3::Z, moment.normalize());
+    gizmos
+        .circle(
+            Isometry3d::from_rotation(rotation),
+            IDEAL_RADIUS,
+            color.with_alpha(0.3),
+        )
+        .resolution(64);
+}
+
+/// Draw a plane at or near infinity as a dashed arrow from the origin toward it, with a bracket
+/// across its tip. The horizon itself has no direction and is drawn as a dashed circle.
+fn draw_ideal_plane(gizmos: &mut Gizmos, plane: &Plane, color: LinearRgba) {
+    let normal = Vec3::from(plane.direction());
+    if normal.length() < f32::EPSILON {
+        let segments = 48;
+        for i in (0..segments).step_by(2) {
+            let angle = |i: usize| i as f32 / segments as f32 * std::f32::consts::TAU;
+            let (a, b) = (angle(i), angle(i + 1));
+            gizmos.line(
+                Vec3::new(a.cos(), 0.0, a.sin()) * IDEAL_RADIUS,
+                Vec3::new(b.cos(), 0.0, b.sin()) * IDEAL_RADIUS,
+                color,
+            );
+        }
+        return;
+    }
+    // The plane lies at -w / |n| along the unit normal
+    let toward = -normal.normalize() * plane.w.signum();
+    let tip = toward * IDEAL_RADIUS;
+    draw_dashed_line(gizmos, Vec3::ZERO, tip, color);
+    gizmos.arrow(tip - toward * 0.5, tip, color);
+    let across = toward.any_orthonormal_vector() * 0.3;
+    gizmos.line(tip - across, tip + across, color);
+}
+
+fn draw_dashed_line(gizmos: &mut Gizmos, start: Vec3, end: Vec3, color: LinearRgba) {
+    let segments = 16;
+    for i in (0..segments).step_by(2) {
+        let a = start.lerp(end, i as f32 / segments as f32);
+        let b = start.lerp(end, (i + 1) as f32 / segments as f32);
+        gizmos.line(a, b, color);
     }
 }
 
 /// Draw just the normal arrow for a PGA plane (used when plane is drawn as mesh)
 fn draw_plane_normal_arrow(gizmos: &mut Gizmos, plane: &Plane, color: LinearRgba) {
+    let Some(normal) = plane.normal_unit_eps(ZERO_TOLERANCE) else {
+        draw_ideal_plane(gizmos, plane, color);
+        return;
+    };
     let point_on_plane = pga_point_on_plane(plane);
-    let normal = Vec3::from(plane.direction()).normalize();
 
     // Draw normal vector arrow
     gizmos.arrow(point_on_plane, point_on_plane + normal, color);
@@ -666,10 +1288,16 @@ fn update_label_positions(
     camera_query: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
     scene_selector: Res<SceneSelector>,
     object_pool: Res<ObjectPool>,
+    display_settings: Res<DisplaySettings>,
     windows: Query<&Window>,
     mut labels: Query<&mut Node>,
     mut object_query: Query<(&mut Visibility, &LinkedLabel)>,
 ) {
+    // Hidden labels are kept hidden by update_visibility, no need to move them around
+    if !display_settings.show_labels {
+        return;
+    }
+
     let Ok((camera, camera_global_transform)) = camera_query.single() else {
         return;
     };
@@ -721,7 +1349,16 @@ fn coordinate_editor_ui(
     mut contexts: EguiContexts,
     mut scene_selector: ResMut<SceneSelector>,
     mut notify_input_changed: EventWriter<InputChangedEvent>,
+    mut dirty_inputs: ResMut<DirtyInputs>,
+    mut display_settings: ResMut<DisplaySettings>,
+    mut playback: ResMut<AnimationPlayback>,
+    mut screenshot: ResMut<ScreenshotSettings>,
+    mut file_error: Local<Option<String>>,
 ) {
+    if screenshot.hide_ui_this_frame {
+        return;
+    }
+    let mut reset_requested = false;
     let scene = scene_selector.current_mut();
 
     let edit_value = |label, ui: &mut egui::Ui, value: &mut f32| {
@@ -773,14 +1410,27 @@ fn coordinate_editor_ui(
             .default_pos([0.0, 0.0])
             .max_width(200.0)
             .show(ctx, |ui| {
+                // Only write the resource back when a box was toggled, so that change detection
+                // does not fire every frame
+                let mut settings = *display_settings;
+                ui.checkbox(&mut settings.show_labels, "Show labels");
+                ui.checkbox(&mut settings.show_axes, "Show axes");
+                display_settings.set_if_neq(settings);
+                if scene.animation.is_some() {
+                    ui.checkbox(&mut playback.playing, "Animate");
+                }
+                ui.checkbox(&mut screenshot.include_ui, "UI in screenshots (F12)");
+                ui.separator();
+
                 let mut points_changed = false;
 
                 for i in 0..scene.input_point_count {
                     if let Some(point) = scene.points.get_mut(i) {
                         let mut vec = Vec3::from(*point);
-                        points_changed |= edit_vec3("Point P", ui, &mut vec, i);
-                        if points_changed {
+                        if edit_vec3("Point P", ui, &mut vec, i) {
                             *point = Point3::new(vec[0], vec[1], vec[2]);
+                            dirty_inputs.mark(Input::Point(i));
+                            points_changed = true;
                         }
                     }
                 }
@@ -788,9 +1438,10 @@ fn coordinate_editor_ui(
                 for i in 0..scene.input_direction_count {
                     if let Some(direction) = scene.directions.get_mut(i) {
                         let mut vec = Vec3::from(*direction);
-                        points_changed |= edit_vec3("Direction D", ui, &mut vec, i);
-                        if points_changed {
+                        if edit_vec3("Direction D", ui, &mut vec, i) {
                             *direction = Direction::new(vec[0], vec[1], vec[2]);
+                            dirty_inputs.mark(Input::Direction(i));
+                            points_changed = true;
                         }
                     }
                 }
@@ -801,16 +1452,94 @@ fn coordinate_editor_ui(
                 for i in 0..scene.input_plane_count {
                     if let Some(plane) = scene.planes.get_mut(i) {
                         let mut values = Vec4::from(*plane);
-                        points_changed |= edit_plane("Plane p", ui, &mut values, i);
-                        if points_changed {
+                        if edit_plane("Plane p", ui, &mut values, i) {
                             *plane = Plane::new(values.x, values.y, values.z, values.w);
+                            dirty_inputs.mark(Input::Plane(i));
+                            points_changed = true;
+                        }
+                    }
+                }
+
+                for i in 0..scene.input_scalar_count {
+                    let range = scene.scalar_range(i);
+                    if let Some(value) = scene.scalars.get_mut(i) {
+                        ui.label(format!("Scalar s{i}:"));
+                        let response = match range {
+                            Some((min, max)) => ui.add(egui::Slider::new(value, min..=max)),
+                            None => ui.add(egui::DragValue::new(value).speed(0.01)),
+                        };
+                        if response.changed() {
+                            dirty_inputs.mark(Input::Scalar(i));
+                            points_changed = true;
                         }
+                        ui.separator();
                     }
                 }
 
+                // Everything after the inputs is computed by the scene. Lines are never inputs.
+                let format_vec3 = |v: Vec3| format!("({:.2}, {:.2}, {:.2})", v.x, v.y, v.z);
+                ui.collapsing("Computed", |ui| {
+                    for (i, point) in scene.points.iter().enumerate() {
+                        if i >= scene.input_point_count {
+                            ui.label(format!("P{i}: {}", format_vec3(Vec3::from(*point))))
+                                .on_hover_text(point.to_string());
+                        }
+                    }
+                    for (i, direction) in scene.directions.iter().enumerate() {
+                        if i >= scene.input_direction_count {
+                            ui.label(format!("D{i}: {}", format_vec3(Vec3::from(*direction))));
+                        }
+                    }
+                    for (i, line) in scene.lines.iter().enumerate() {
+                        ui.label(format!(
+                            "L{i}: direction {}, moment {}",
+                            format_vec3(Vec3::from(line.direction())),
+                            format_vec3(Vec3::from(line.bulk()))
+                        ))
+                        .on_hover_text(line.to_string());
+                    }
+                    for (i, plane) in scene.planes.iter().enumerate() {
+                        if i >= scene.input_plane_count {
+                            ui.label(format!(
+                                "p{i}: normal {}, offset {:.2}",
+                                format_vec3(Vec3::from(plane.direction())),
+                                plane.w
+                            ))
+                            .on_hover_text(plane.to_string());
+                        }
+                    }
+                });
+                ui.separator();
+
+                ui.horizontal(|ui| {
+                    if ui.button("Save").clicked() {
+                        *file_error = scene.save_inputs(PGAScene::INPUTS_FILE).err();
+                    }
+                    if ui.button("Load").clicked() {
+                        match scene.load_inputs(PGAScene::INPUTS_FILE) {
+                            Ok(()) => {
+                                *file_error = None;
+                                dirty_inputs.mark_all();
+                                points_changed = true;
+                            }
+                            Err(error) => *file_error = Some(error),
+                        }
+                    }
+                    reset_requested = ui.button("Reset").clicked();
+                });
+                if let Some(error) = &*file_error {
+                    ui.colored_label(egui::Color32::RED, error);
+                }
+
                 if points_changed {
                     notify_input_changed.write(InputChangedEvent);
                 }
             });
     }
+
+    if reset_requested {
+        scene_selector.reset_current();
+        dirty_inputs.mark_all();
+        notify_input_changed.write(InputChangedEvent);
+    }
 }
@@ -1,11 +1,17 @@
-use crate::pgai::{BulkWeight, Direction, Dual, Line, Plane, Point3};
-use crate::visualization::SceneSelector;
+use crate::pgai::{Direction, Line, Motor, Plane, Point3};
+use crate::visualization::expr::{Expr, Input, Inputs, Value, dirty_steps, evaluate};
+use crate::visualization::{DirtyInputs, SceneChangedEvent, SceneColor, SceneSelector};
 
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
-#[derive(Default)]
+/// A scene as browsed in the app. Scenes serialize whole, inputs, derived entities and how they
+/// are computed, so they can be shared as files and opened with `--scene <file>`. Fields missing
+/// from files saved by older versions take their default.
+#[derive(Default, Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(default)]
 pub struct PGAScene {
-    pub name: &'static str,
+    pub name: String,
     pub points: Vec<Point3>,
     pub lines: Vec<Line>,
     pub planes: Vec<Plane>,
@@ -13,6 +19,35 @@ pub struct PGAScene {
     pub input_point_count: usize,
     pub input_plane_count: usize,
     pub input_direction_count: usize,
+    /// Numbers the expressions read with `Expr::Scalar`, such as angles. All of them are inputs.
+    pub scalars: Vec<f32>,
+    pub input_scalar_count: usize,
+    /// The `(min, max)` of the slider editing each scalar, by index. Scalars without a range are
+    /// edited with a drag value.
+    pub scalar_ranges: Vec<Option<(f32, f32)>>,
+    /// Motion applied to the inputs per unit of time while the animation plays, usually a
+    /// rotation of one radian
+    pub animation: Option<Motor>,
+    /// Units of `animation` per second, radians per second for a one radian rotation
+    pub angular_velocity: f32,
+    /// Colors of the entities, by index. Entities without one use the default color of their kind.
+    pub point_colors: Vec<SceneColor>,
+    pub line_colors: Vec<SceneColor>,
+    pub plane_colors: Vec<SceneColor>,
+    pub direction_colors: Vec<SceneColor>,
+    /// How the derived entities are computed from the inputs, see `recompute`
+    pub expressions: Vec<Expr>,
+}
+
+/// The user editable entities of a scene, as saved to and loaded from disk
+#[derive(Serialize, Deserialize)]
+pub struct SceneInputs {
+    pub scene: String,
+    pub points: Vec<Point3>,
+    pub directions: Vec<Direction>,
+    pub planes: Vec<Plane>,
+    #[serde(default)]
+    pub scalars: Vec<f32>,
 }
 
 impl PGAScene {
@@ -45,8 +80,288 @@ impl PGAScene {
         "The projection of point P0 onto plane p0: P1 = p0 & (P0 ^ !p0.dir)";
     pub const PROJECT_LINE_ONTO_PLANE: &str =
         "The projection of line L0 onto plane p0: p1 = L0 ^ !p0.dir; L1 = p0 & p1";
-    /// Setup the initial scene with camera and lighting
+
+    // Motion
+    pub const POINT_ORBITS_A_LINE: &str =
+        "A motor M around line L0 moves point P0 along a circle: P0 = M P0 ~M";
+    pub const REFLECT_POINT_ACROSS_PLANE: &str =
+        "The reflection of point P0 across plane p0: P1 = p0 P0 p0";
+    pub const ROTATE_LINE_ABOUT_AXIS: &str =
+        "Line L0 rotated about axis L1 by angle s0: L2 = M L0 ~M, M = exp(s0 / 2 L1)";
+
+    pub const INPUTS_FILE: &str = "pga_scene_inputs.json";
+
+    pub const POINT_COLOR: SceneColor = SceneColor::WHITE;
+    pub const LINE_COLOR: SceneColor = SceneColor::YELLOW;
+    pub const PLANE_COLOR: SceneColor = SceneColor::CYAN;
+    pub const DIRECTION_COLOR: SceneColor = SceneColor::ORANGE;
+
+    /// An empty scene, to be filled with the `with_*` methods. Points, directions and planes
+    /// added this way are inputs the user can edit, placed after the other inputs and before
+    /// the entities the expressions derive.
+    pub fn new() -> Self {
+        PGAScene::default()
+    }
+
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Adds an input point, drawn in `color` or the default point color.
+    pub fn with_point(mut self, point: Point3, color: Option<SceneColor>) -> Self {
+        insert_colored(
+            &mut self.points,
+            &mut self.point_colors,
+            self.input_point_count,
+            point,
+            color,
+            Self::POINT_COLOR,
+        );
+        self.input_point_count += 1;
+        self
+    }
+
+    pub fn with_direction(mut self, direction: Direction, color: Option<SceneColor>) -> Self {
+        insert_colored(
+            &mut self.directions,
+            &mut self.direction_colors,
+            self.input_direction_count,
+            direction,
+            color,
+            Self::DIRECTION_COLOR,
+        );
+        self.input_direction_count += 1;
+        self
+    }
+
+    /// Lines are not editable, so this only adds the line to the scene.
+    pub fn with_line(mut self, line: Line, color: Option<SceneColor>) -> Self {
+        let index = self.lines.len();
+        insert_colored(
+            &mut self.lines,
+            &mut self.line_colors,
+            index,
+            line,
+            color,
+            Self::LINE_COLOR,
+        );
+        self
+    }
+
+    pub fn with_plane(mut self, plane: Plane, color: Option<SceneColor>) -> Self {
+        insert_colored(
+            &mut self.planes,
+            &mut self.plane_colors,
+            self.input_plane_count,
+            plane,
+            color,
+            Self::PLANE_COLOR,
+        );
+        self.input_plane_count += 1;
+        self
+    }
+
+    /// Adds an input scalar, edited with a slider over `range` or, without one, a drag value.
+    pub fn with_scalar(mut self, value: f32, range: Option<(f32, f32)>) -> Self {
+        self.scalar_ranges.resize(self.scalars.len(), None);
+        self.scalars.insert(self.input_scalar_count, value);
+        self.scalar_ranges.insert(self.input_scalar_count, range);
+        self.input_scalar_count += 1;
+        self
+    }
+
+    /// Sets the steps that compute the derived entities from the inputs.
+    pub fn with_expressions(mut self, expressions: Vec<Expr>) -> Self {
+        self.expressions = expressions;
+        self
+    }
+
+    pub fn point_color(&self, index: usize) -> SceneColor {
+        color_at(&self.point_colors, index, Self::POINT_COLOR)
+    }
+
+    pub fn line_color(&self, index: usize) -> SceneColor {
+        color_at(&self.line_colors, index, Self::LINE_COLOR)
+    }
+
+    pub fn plane_color(&self, index: usize) -> SceneColor {
+        color_at(&self.plane_colors, index, Self::PLANE_COLOR)
+    }
+
+    pub fn direction_color(&self, index: usize) -> SceneColor {
+        color_at(&self.direction_colors, index, Self::DIRECTION_COLOR)
+    }
+
+    pub fn scalar_range(&self, index: usize) -> Option<(f32, f32)> {
+        self.scalar_ranges.get(index).copied().flatten()
+    }
+
+    /// The box around the points and the supports of the lines and planes, as minimum and
+    /// maximum corners. Ideal entities have no position and are left out, so a scene of only
+    /// directions has no bounds.
+    pub fn bounds(&self) -> Option<(Vec3, Vec3)> {
+        let points = self.points.iter().copied();
+        let lines = self.lines.iter().map(Line::support);
+        let planes = self.planes.iter().map(Plane::support);
+        points
+            .chain(lines)
+            .chain(planes)
+            .map(Vec3::from)
+            .filter(|position| position.is_finite())
+            .fold(None, |bounds, position| match bounds {
+                None => Some((position, position)),
+                Some((min, max)) => Some((min.min(position), max.max(position))),
+            })
+    }
+
+    pub fn inputs(&self) -> SceneInputs {
+        SceneInputs {
+            scene: self.name.clone(),
+            points: self
+                .points
+                .iter()
+                .take(self.input_point_count)
+                .copied()
+                .collect(),
+            directions: self
+                .directions
+                .iter()
+                .take(self.input_direction_count)
+                .copied()
+                .collect(),
+            planes: self
+                .planes
+                .iter()
+                .take(self.input_plane_count)
+                .copied()
+                .collect(),
+            scalars: self
+                .scalars
+                .iter()
+                .take(self.input_scalar_count)
+                .copied()
+                .collect(),
+        }
+    }
+
+    /// Replaces the inputs of the scene, which must have been saved from the same scene.
+    pub fn set_inputs(&mut self, inputs: SceneInputs) -> Result<(), String> {
+        if inputs.scene != self.name {
+            return Err(format!("The file holds inputs for \"{}\"", inputs.scene));
+        }
+        if inputs.points.len() != self.input_point_count
+            || inputs.directions.len() != self.input_direction_count
+            || inputs.planes.len() != self.input_plane_count
+            || inputs.scalars.len() != self.input_scalar_count
+        {
+            return Err("The file does not match the inputs of this scene".to_string());
+        }
+        for (point, input) in self.points.iter_mut().zip(inputs.points) {
+            *point = input;
+        }
+        for (direction, input) in self.directions.iter_mut().zip(inputs.directions) {
+            *direction = input;
+        }
+        for (plane, input) in self.planes.iter_mut().zip(inputs.planes) {
+            *plane = input;
+        }
+        for (scalar, input) in self.scalars.iter_mut().zip(inputs.scalars) {
+            *scalar = input;
+        }
+        Ok(())
+    }
+
+    pub fn save_inputs(&self, path: &str) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(&self.inputs()).map_err(|e| e.to_string())?;
+        std::fs::write(path, json).map_err(|e| format!("Could not save {path}: {e}"))
+    }
+
+    pub fn load_inputs(&mut self, path: &str) -> Result<(), String> {
+        let json =
+            std::fs::read_to_string(path).map_err(|e| format!("Could not load {path}: {e}"))?;
+        let inputs = serde_json::from_str(&json).map_err(|e| format!("Invalid {path}: {e}"))?;
+        self.set_inputs(inputs)
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        std::fs::write(path, json).map_err(|e| format!("Could not save {path}: {e}"))
+    }
+
+    pub fn load(path: &str) -> Result<PGAScene, String> {
+        let json =
+            std::fs::read_to_string(path).map_err(|e| format!("Could not load {path}: {e}"))?;
+        let scene: PGAScene =
+            serde_json::from_str(&json).map_err(|e| format!("Invalid {path}: {e}"))?;
+        scene
+            .validate()
+            .map_err(|e| format!("Invalid {path}: {e}"))?;
+        Ok(scene)
+    }
+
+    /// Checks what serde cannot: the input counts fit the entities, which `recompute` slices
+    /// by them, and there are no more colors or scalar ranges than entities and scalars.
+    fn validate(&self) -> Result<(), String> {
+        let counts = [
+            ("point", self.input_point_count, self.points.len()),
+            (
+                "direction",
+                self.input_direction_count,
+                self.directions.len(),
+            ),
+            ("plane", self.input_plane_count, self.planes.len()),
+            ("scalar", self.input_scalar_count, self.scalars.len()),
+        ];
+        for (kind, count, len) in counts {
+            if count > len {
+                return Err(format!("{count} input {kind}s but only {len} {kind}s"));
+            }
+        }
+        let colors = [
+            ("point", self.point_colors.len(), self.points.len()),
+            ("line", self.line_colors.len(), self.lines.len()),
+            ("plane", self.plane_colors.len(), self.planes.len()),
+            (
+                "direction",
+                self.direction_colors.len(),
+                self.directions.len(),
+            ),
+        ];
+        for (kind, count, len) in colors {
+            if count > len {
+                return Err(format!("{count} {kind} colors but only {len} {kind}s"));
+            }
+        }
+        if self.scalar_ranges.len() > self.scalars.len() {
+            return Err("More scalar ranges than scalars".to_string());
+        }
+        if self
+            .scalar_ranges
+            .iter()
+            .flatten()
+            .any(|(min, max)| min > max)
+        {
+            return Err("A scalar range has its minimum above its maximum".to_string());
+        }
+        Ok(())
+    }
+
+    /// Setup the initial scene with camera and lighting. A scene file given with `--scene <file>`
+    /// is opened first, ahead of the library.
     pub fn setup(mut scene_selector: ResMut<SceneSelector>) {
+        let mut scenes = PGAScene::library();
+        if let Some(path) = scene_arg() {
+            match PGAScene::load(&path) {
+                Ok(scene) => scenes.insert(0, scene),
+                Err(message) => error!("{message}"),
+            }
+        }
+        scene_selector.set_scenes(scenes);
+    }
+
+    /// The example scenes, in the order they are browsed
+    pub fn library() -> Vec<PGAScene> {
         let p0 = Point3::new(1.0, 0.0, 0.0);
         let p1 = Point3::new(0.0, 1.0, 0.0);
         let p2 = Point3::new(0.0, 0.0, 1.0);
@@ -67,9 +382,9 @@ impl PGAScene {
         let plane1 = p[3] ^ p[4] ^ p[5];
         let plane2 = p[6] ^ p[7] ^ p[8];
 
-        scene_selector.scenes = vec![
+        vec![
             PGAScene {
-                name: PGAScene::EMPTY_SCENE,
+                name: PGAScene::EMPTY_SCENE.into(),
                 points: vec![],
                 lines: vec![],
                 planes: vec![],
@@ -77,44 +392,73 @@ impl PGAScene {
                 ..default()
             },
             PGAScene {
-                name: PGAScene::TWO_POINTS_JOIN_IN_A_LINE,
+                name: PGAScene::TWO_POINTS_JOIN_IN_A_LINE.into(),
                 points: vec![p0.clone(), p1.clone()],
                 lines: vec![Line::through_origin(1.0, 0.0, 0.0)],
                 planes: vec![],
                 directions: vec![],
                 input_point_count: 2,
+                expressions: vec![
+                    Expr::Point(0),
+                    Expr::Point(1),
+                    Expr::Join(0, 1),
+                    Expr::Show(2),
+                ],
                 ..default()
             },
             PGAScene {
-                name: PGAScene::DIRECTIONS_AND_POINTS_JOIN_IN_A_LINE,
+                name: PGAScene::DIRECTIONS_AND_POINTS_JOIN_IN_A_LINE.into(),
                 points: vec![Point3::new(1.0, 1.0, 0.0)],
                 lines: vec![Line::through_origin(0.0, 0.0, 0.0)],
                 planes: vec![],
                 directions: vec![Direction::new(0.0, 1.0, 0.0)],
                 input_point_count: 1,
                 input_direction_count: 1,
+                expressions: vec![
+                    Expr::Point(0),
+                    Expr::Direction(0),
+                    Expr::Join(0, 1),
+                    Expr::Show(2),
+                ],
                 ..default()
             },
             PGAScene {
-                name: PGAScene::THREE_POINTS_JOIN_IN_A_PLANE,
+                name: PGAScene::THREE_POINTS_JOIN_IN_A_PLANE.into(),
                 points: vec![p0.clone(), p1.clone(), p2.clone()],
                 lines: vec![],
                 planes: vec![Plane::new(1.0, 0.0, 0.0, 0.0)],
                 directions: vec![],
                 input_point_count: 3,
+                expressions: vec![
+                    Expr::Point(0),
+                    Expr::Point(1),
+                    Expr::Point(2),
+                    Expr::Join(0, 1),
+                    Expr::Join(3, 2),
+                    Expr::Show(4),
+                ],
                 ..default()
             },
             PGAScene {
-                name: PGAScene::LINE_AND_POINT_JOIN_IN_A_PLANE,
+                name: PGAScene::LINE_AND_POINT_JOIN_IN_A_PLANE.into(),
                 points: vec![p0.clone(), p1.clone(), p2.clone()],
                 lines: vec![p1 ^ p2],
                 planes: vec![Plane::new(1.0, 0.0, 0.0, 0.0)],
                 directions: vec![],
                 input_point_count: 3,
+                expressions: vec![
+                    Expr::Point(0),
+                    Expr::Point(1),
+                    Expr::Point(2),
+                    Expr::Join(1, 2),
+                    Expr::Show(3),
+                    Expr::Join(3, 0),
+                    Expr::Show(5),
+                ],
                 ..default()
             },
             PGAScene {
-                name: PGAScene::LINE_AND_PLANE_MEET_IN_A_POINT,
+                name: PGAScene::LINE_AND_PLANE_MEET_IN_A_POINT.into(),
                 points: vec![p0.clone(), p1.clone(), p2.clone()],
                 lines: vec![p1 ^ p2],
                 planes: vec![Plane::new(1.0, 0.0, 1.0, 1.0)],
@@ -122,28 +466,51 @@ impl PGAScene {
                 input_point_count: 2,
                 input_plane_count: 1,
 
+                expressions: vec![
+                    Expr::Plane(0),
+                    Expr::Point(0),
+                    Expr::Point(1),
+                    Expr::Join(1, 2),
+                    Expr::Show(3),
+                    Expr::Meet(3, 0),
+                    Expr::Show(5),
+                ],
                 ..default()
             },
             PGAScene {
-                name: PGAScene::TWO_PLANES_MEET_IN_A_LINE,
+                name: PGAScene::TWO_PLANES_MEET_IN_A_LINE.into(),
                 points: vec![],
                 lines: vec![Line::through_origin(1.0, 0.0, 0.0)],
                 planes: vec![plane0.clone(), plane1.clone()],
                 directions: vec![],
                 input_plane_count: 2,
+                expressions: vec![
+                    Expr::Plane(0),
+                    Expr::Plane(1),
+                    Expr::Meet(0, 1),
+                    Expr::Show(2),
+                ],
                 ..default()
             },
             PGAScene {
-                name: PGAScene::THREE_PLANES_MEET_IN_A_POINT,
+                name: PGAScene::THREE_PLANES_MEET_IN_A_POINT.into(),
                 points: vec![Point3::new(0.0, 0.0, 0.0)],
                 lines: vec![],
                 planes: vec![plane0, plane1, plane2],
                 directions: vec![],
                 input_plane_count: 3,
+                expressions: vec![
+                    Expr::Plane(0),
+                    Expr::Plane(1),
+                    Expr::Plane(2),
+                    Expr::Meet(0, 1),
+                    Expr::Meet(3, 2),
+                    Expr::Show(4),
+                ],
                 ..default()
             },
             PGAScene {
-                name: PGAScene::PLANE_PERP_THROUGH_LINE,
+                name: PGAScene::PLANE_PERP_THROUGH_LINE.into(),
                 points: vec![p0.clone(), p1.clone()],
                 lines: vec![Line::through_origin(1.0, 0.0, 0.0)],
                 planes: vec![
@@ -153,29 +520,56 @@ impl PGAScene {
                 directions: vec![],
                 input_plane_count: 1,
                 input_point_count: 2,
+                expressions: vec![
+                    Expr::Point(0),
+                    Expr::Point(1),
+                    Expr::Plane(0),
+                    Expr::Join(0, 1),
+                    Expr::Show(3),
+                    Expr::Orthogonal(2),
+                    Expr::Join(3, 5),
+                    Expr::Show(6),
+                ],
                 ..default()
             },
             PGAScene {
-                name: PGAScene::LINE_PERP_THROUGH_POINT,
+                name: PGAScene::LINE_PERP_THROUGH_POINT.into(),
                 points: vec![p0.clone()],
                 lines: vec![Line::through_origin(1.0, 0.0, 0.0)],
                 planes: vec![Plane::new(1.0, 0.0, 1.0, 1.0)],
                 directions: vec![],
                 input_plane_count: 1,
                 input_point_count: 1,
+                expressions: vec![
+                    Expr::Point(0),
+                    Expr::Plane(0),
+                    Expr::Orthogonal(1),
+                    Expr::Join(0, 2),
+                    Expr::Show(3),
+                ],
                 ..default()
             },
             PGAScene {
-                name: PGAScene::PLANE_PERP_THROUGH_POINT,
+                name: PGAScene::PLANE_PERP_THROUGH_POINT.into(),
                 points: vec![Point3::new(1.0, 0.0, 1.0), p1.clone(), p2.clone()],
                 lines: vec![p1 ^ p2],
                 planes: vec![Plane::new(1.0, 0.0, 0.0, 0.0)],
                 directions: vec![],
                 input_point_count: 3,
+                expressions: vec![
+                    Expr::Point(0),
+                    Expr::Point(1),
+                    Expr::Point(2),
+                    Expr::Join(1, 2),
+                    Expr::Show(3),
+                    Expr::Orthogonal(3),
+                    Expr::Join(0, 5),
+                    Expr::Show(6),
+                ],
                 ..default()
             },
             PGAScene {
-                name: PGAScene::PROJECT_PLANE_ONTO_POINT,
+                name: PGAScene::PROJECT_PLANE_ONTO_POINT.into(),
                 points: vec![Point3::new(1.0, 2.0, 3.0)],
                 lines: vec![Line::through_origin(1.0, 0.0, 0.0)],
                 planes: vec![
@@ -185,20 +579,38 @@ impl PGAScene {
                 directions: vec![],
                 input_point_count: 1,
                 input_plane_count: 1,
+                expressions: vec![
+                    Expr::Point(0),
+                    Expr::Plane(0),
+                    Expr::Orthogonal(1),
+                    Expr::Join(2, 0),
+                    Expr::Show(3),
+                    Expr::Orthogonal(3),
+                    Expr::Join(0, 5),
+                    Expr::Show(6),
+                ],
                 ..default()
             },
             PGAScene {
-                name: PGAScene::PROJECT_POINT_ONTO_PLANE,
+                name: PGAScene::PROJECT_POINT_ONTO_PLANE.into(),
                 points: vec![Point3::new(0.0, 1.0, 0.0), Point3::new(0.0, 0.0, 0.0)],
                 lines: vec![],
                 planes: vec![Plane::new(-1.0, 1.0, 1.0, 1.0)],
                 directions: vec![],
                 input_point_count: 1,
                 input_plane_count: 1,
+                expressions: vec![
+                    Expr::Point(0),
+                    Expr::Plane(0),
+                    Expr::Orthogonal(1),
+                    Expr::Join(0, 2),
+                    Expr::Meet(1, 3),
+                    Expr::Show(4),
+                ],
                 ..default()
             },
             PGAScene {
-                name: PGAScene::PROJECT_LINE_ONTO_PLANE,
+                name: PGAScene::PROJECT_LINE_ONTO_PLANE.into(),
                 points: vec![p0.clone(), p1.clone()],
                 lines: vec![p1 ^ p2, Line::through_origin(0.0, 0.0, 0.0)],
                 planes: vec![Plane::new(1.0, 0.0, 1.0, 1.0), Plane::FORWARD],
@@ -206,135 +618,569 @@ impl PGAScene {
                 input_point_count: 2,
                 input_plane_count: 1,
 
+                expressions: vec![
+                    Expr::Point(0),
+                    Expr::Point(1),
+                    Expr::Plane(0),
+                    Expr::Join(0, 1),
+                    Expr::Show(3),
+                    Expr::Orthogonal(2),
+                    Expr::Join(3, 5),
+                    Expr::Show(6),
+                    Expr::Meet(2, 6),
+                    Expr::Show(8),
+                ],
                 ..default()
             },
-        ];
+            PGAScene {
+                name: PGAScene::REFLECT_POINT_ACROSS_PLANE.into(),
+                points: vec![Point3::new(1.0, 0.5, 0.5), Point3::new(0.0, 0.0, 0.0)],
+                planes: vec![Plane::new(1.0, 1.0, 0.0, 0.0)],
+                input_point_count: 1,
+                input_plane_count: 1,
+                expressions: vec![
+                    Expr::Point(0),
+                    Expr::Plane(0),
+                    Expr::Reflect(1, 0),
+                    Expr::Show(2),
+                ],
+                ..default()
+            },
+            PGAScene {
+                name: PGAScene::ROTATE_LINE_ABOUT_AXIS.into(),
+                points: vec![
+                    Point3::new(1.0, 0.0, 0.0),
+                    Point3::new(1.0, 1.0, 1.0),
+                    Point3::new(0.0, 0.0, 0.0),
+                ],
+                directions: vec![Direction::new(0.0, 1.0, 0.0)],
+                lines: vec![Line::X_AXIS, Line::Y_AXIS, Line::X_AXIS],
+                scalars: vec![std::f32::consts::FRAC_PI_2],
+                scalar_ranges: vec![Some((-std::f32::consts::PI, std::f32::consts::PI))],
+                input_point_count: 3,
+                input_direction_count: 1,
+                input_scalar_count: 1,
+                expressions: vec![
+                    Expr::Point(0),
+                    Expr::Point(1),
+                    Expr::Join(0, 1),
+                    Expr::Show(2),
+                    Expr::Point(2),
+                    Expr::Direction(0),
+                    Expr::Join(4, 5),
+                    Expr::Show(6),
+                    Expr::Scalar(0),
+                    Expr::Rotate(6, 2, 8),
+                    Expr::Show(9),
+                ],
+                ..default()
+            },
+            PGAScene {
+                name: PGAScene::POINT_ORBITS_A_LINE.into(),
+                points: vec![Point3::new(2.0, 0.0, 0.0)],
+                lines: vec![Line::Y_AXIS],
+                planes: vec![],
+                directions: vec![],
+                input_point_count: 1,
+                animation: Some(Motor::from_rotation(Line::Y_AXIS, 1.0)),
+                angular_velocity: 1.0,
+                ..default()
+            },
+        ]
     }
 
-    pub fn rebuild(mut scene_selector: ResMut<SceneSelector>) {
+    pub fn rebuild(
+        mut scene_selector: ResMut<SceneSelector>,
+        mut dirty_inputs: ResMut<DirtyInputs>,
+        mut scene_changed: EventReader<SceneChangedEvent>,
+    ) {
+        // A different scene, or inputs changed all at once, always need a full recompute
+        let changed = dirty_inputs.take();
+        let scene = scene_selector.current_mut();
+        if scene_changed.read().count() == 0
+            && let Some(changed) = changed
+            && !scene.needs_recompute(&changed)
+        {
+            return;
+        }
         info!("Input/Scene changed, rebuilding scene...");
+        scene.recompute();
+    }
 
-        let scene = scene_selector.current_mut();
+    /// Whether editing the `changed` inputs moves anything the scene shows.
+    pub fn needs_recompute(&self, changed: &[Input]) -> bool {
+        dirty_steps(&self.expressions, changed)
+            .into_iter()
+            .zip(&self.expressions)
+            .any(|(dirty, expr)| dirty && matches!(expr, Expr::Show(_)))
+    }
 
-        match scene.name {
-            PGAScene::TWO_POINTS_JOIN_IN_A_LINE => {
-                let p0 = scene.points[0];
-                let p1 = scene.points[1];
-                // Output
-                scene.lines[0] = p0 ^ p1;
-            }
-            PGAScene::DIRECTIONS_AND_POINTS_JOIN_IN_A_LINE => {
-                // Inputs
-                let p0 = scene.points[0];
-                let d0 = scene.directions[0];
-                // Output
-                scene.lines[0] = p0 ^ d0;
-            }
-            PGAScene::THREE_POINTS_JOIN_IN_A_PLANE => {
-                // Inputs
-                let p0 = scene.points[0];
-                let p1 = scene.points[1];
-                let p2 = scene.points[2];
-                // Output
-                scene.planes[0] = p0 ^ p1 ^ p2;
+    /// Recomputes the derived entities of the scene from its inputs, without touching Bevy. Every
+    /// `Expr::Show` step writes its result to the next slot of its kind after the inputs, so the
+    /// first line shown is `lines[0]` and the first point shown follows the input points.
+    pub fn recompute(&mut self) {
+        if self.expressions.is_empty() {
+            return;
+        }
+        let inputs = Inputs {
+            points: &self.points[..self.input_point_count],
+            directions: &self.directions[..self.input_direction_count],
+            planes: &self.planes[..self.input_plane_count],
+            scalars: &self.scalars[..self.input_scalar_count],
+        };
+        let values = match evaluate(&self.expressions, inputs) {
+            Ok(values) => values,
+            Err(message) => {
+                error!("Cannot rebuild \"{}\": {message}", self.name);
+                return;
             }
-            PGAScene::LINE_AND_POINT_JOIN_IN_A_PLANE => {
-                // Inputs
-                let p0 = scene.points[0];
-                let p1 = scene.points[1];
-                let p2 = scene.points[2];
-
-                // Output
-                let line = p1 ^ p2;
-                scene.lines[0] = line;
-                scene.planes[0] = line ^ p0;
+        };
+
+        let mut next_point = self.input_point_count;
+        let mut next_direction = self.input_direction_count;
+        let mut next_line = 0;
+        let mut next_plane = self.input_plane_count;
+        for (expr, value) in self.expressions.iter().zip(values) {
+            if !matches!(expr, Expr::Show(_)) {
+                continue;
             }
-            PGAScene::LINE_AND_PLANE_MEET_IN_A_POINT => {
-                // Inputs
-                let plane0 = scene.planes[0];
-                let p0 = scene.points[0];
-                let p1 = scene.points[1];
-
-                // Output
-                let line = p0 ^ p1;
-                scene.lines[0] = line;
-                scene.points[2] = Point3::from(line & plane0);
+            match value {
+                Value::Point(point) => set_slot(&mut self.points, &mut next_point, point),
+                Value::Direction(direction) => {
+                    set_slot(&mut self.directions, &mut next_direction, direction)
+                }
+                Value::Line(line) => set_slot(&mut self.lines, &mut next_line, line),
+                Value::Plane(plane) => set_slot(&mut self.planes, &mut next_plane, plane),
+                // Not something that can be drawn, evaluate refuses to show it
+                Value::LineMoment(_) | Value::Scalar(_) => {}
             }
-            PGAScene::TWO_PLANES_MEET_IN_A_LINE => {
-                let plane0 = scene.planes[0];
-                let plane1 = scene.planes[1];
+        }
+    }
+}
 
-                // Output
-                scene.lines[0] = plane0 & plane1;
-            }
-            PGAScene::THREE_PLANES_MEET_IN_A_POINT => {
-                let plane0 = scene.planes[0];
-                let plane1 = scene.planes[1];
-                let plane2 = scene.planes[2];
+/// Writes `value` at `*next`, growing `entities` when needed, and moves on to the next slot.
+fn set_slot<T>(entities: &mut Vec<T>, next: &mut usize, value: T) {
+    if let Some(slot) = entities.get_mut(*next) {
+        *slot = value;
+    } else {
+        entities.push(value);
+    }
+    *next += 1;
+}
 
-                // Output
-                scene.points[0] = Point3::from(plane0 & plane1 & plane2);
-            }
-            PGAScene::PLANE_PERP_THROUGH_LINE => {
-                let p0 = scene.points[0];
-                let p1 = scene.points[1];
-                let plane0 = scene.planes[0];
-
-                // Output
-                let line = p0 ^ p1;
-                scene.lines[0] = line;
-                scene.planes[1] = line ^ !plane0.direction();
-            }
-            PGAScene::LINE_PERP_THROUGH_POINT => {
-                let p0 = scene.points[0];
-                let plane0 = scene.planes[0];
+/// Inserts an entity and its color at `index`, first giving the entities without a color the
+/// default one so that the colors stay aligned with the entities.
+fn insert_colored<T>(
+    entities: &mut Vec<T>,
+    colors: &mut Vec<SceneColor>,
+    index: usize,
+    entity: T,
+    color: Option<SceneColor>,
+    default: SceneColor,
+) {
+    colors.resize(entities.len(), default);
+    entities.insert(index, entity);
+    colors.insert(index, color.unwrap_or(default));
+}
 
-                // Output
-                scene.lines[0] = p0 ^ !plane0.direction();
-            }
-            PGAScene::PLANE_PERP_THROUGH_POINT => {
-                // Inputs
-                let p0 = scene.points[0];
-                let p1 = scene.points[1];
-                let p2 = scene.points[2];
-
-                // Output
-                let line = p1 ^ p2;
-                scene.lines[0] = line;
-                scene.planes[0] = p0 ^ !line.direction();
-            }
-            PGAScene::PROJECT_PLANE_ONTO_POINT => {
-                let p0 = scene.points[0];
-                let plane0 = scene.planes[0];
-
-                // Output
-                let line = !plane0.direction() ^ p0;
-                scene.lines[0] = line;
-                scene.planes[1] = p0 ^ !line.direction();
-            }
-            PGAScene::PROJECT_POINT_ONTO_PLANE => {
-                let p0 = scene.points[0];
-                let plane0 = scene.planes[0];
+fn color_at(colors: &[SceneColor], index: usize, default: SceneColor) -> SceneColor {
+    colors.get(index).copied().unwrap_or(default)
+}
 
-                // Output
-                let line = p0 ^ !plane0.direction();
-                scene.points[1] = Point3::from(plane0 & line);
-            }
-            PGAScene::PROJECT_LINE_ONTO_PLANE => {
-                let p0 = scene.points[0];
-                let p1 = scene.points[1];
-                let plane0 = scene.planes[0];
+/// The file following `--scene` on the command line
+fn scene_arg() -> Option<String> {
+    let mut args = std::env::args().skip_while(|arg| arg != "--scene");
+    args.next()?;
+    args.next()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pgai::GeometricEntity;
+
+    #[test]
+    fn builder_counts_inputs() {
+        let scene = PGAScene::new()
+            .with_name("Custom")
+            .with_point(Point3::new(1.0, 0.0, 0.0), None)
+            .with_point(Point3::new(0.0, 1.0, 0.0), Some(SceneColor::RED))
+            .with_line(Line::X_AXIS, None)
+            .with_plane(Plane::UP, Some(SceneColor::GREEN));
+        assert_eq!(scene.name, "Custom");
+        assert_eq!(scene.points.len(), 2);
+        assert_eq!(scene.input_point_count, 2);
+        assert_eq!(scene.lines.len(), 1);
+        assert_eq!(scene.input_plane_count, 1);
+        assert_eq!(scene.input_direction_count, 0);
+    }
+
+    #[test]
+    fn builder_keeps_colors_aligned_with_entities() {
+        let scene = PGAScene::new()
+            .with_point(Point3::new(1.0, 0.0, 0.0), None)
+            .with_point(Point3::new(0.0, 1.0, 0.0), Some(SceneColor::RED))
+            .with_line(Line::X_AXIS, None)
+            .with_plane(Plane::UP, Some(SceneColor::GREEN))
+            .with_direction(Direction::new(0.0, 0.0, 1.0), None);
+        assert_eq!(scene.point_colors.len(), scene.points.len());
+        assert_eq!(scene.line_colors.len(), scene.lines.len());
+        assert_eq!(scene.plane_colors.len(), scene.planes.len());
+        assert_eq!(scene.direction_colors.len(), scene.directions.len());
+        assert_eq!(scene.point_color(0), PGAScene::POINT_COLOR);
+        assert_eq!(scene.point_color(1), SceneColor::RED);
+
+        // Scenes written out without colors get the defaults, also for entities added later
+        let mut scene = PGAScene {
+            points: vec![Point3::new(0.0, 0.0, 0.0)],
+            input_point_count: 1,
+            ..default()
+        };
+        assert_eq!(scene.point_color(0), PGAScene::POINT_COLOR);
+        scene = scene.with_point(Point3::new(1.0, 1.0, 1.0), Some(SceneColor::BLUE));
+        assert_eq!(
+            scene.point_colors,
+            vec![PGAScene::POINT_COLOR, SceneColor::BLUE]
+        );
+    }
+
+    #[test]
+    fn entities_added_to_a_library_scene_survive_recompute() {
+        // What `add_point` and `add_plane` from JavaScript do to the current scene
+        let mut scene = library_scene(PGAScene::LINE_AND_PLANE_MEET_IN_A_POINT);
+        scene.recompute();
+        let meet = scene.points[2];
+        let mut scene = scene
+            .with_point(Point3::new(4.0, 5.0, 6.0), None)
+            .with_plane(Plane::UP, None);
+        scene.recompute();
+        assert_eq!(scene.points.len(), 4);
+        assert_eq!(scene.points[2], Point3::new(4.0, 5.0, 6.0));
+        assert_eq!(scene.points[3], meet);
+        assert_eq!(scene.planes[1], Plane::UP);
+        assert_eq!(scene.inputs().points.len(), 3);
+    }
+
+    #[test]
+    fn builder_keeps_inputs_before_derived_entities() {
+        let scene = library_scene(PGAScene::PROJECT_POINT_ONTO_PLANE)
+            .with_point(Point3::new(2.0, 0.0, 0.0), Some(SceneColor::RED))
+            .with_plane(Plane::UP, None);
+        assert_eq!(scene.input_point_count, 2);
+        assert_eq!(scene.points[1], Point3::new(2.0, 0.0, 0.0));
+        assert_eq!(scene.point_color(1), SceneColor::RED);
+        // The projection, derived from the first point, stays last
+        assert_eq!(scene.points[2], Point3::new(0.0, 0.0, 0.0));
+        assert_eq!(scene.point_color(2), PGAScene::POINT_COLOR);
+        assert_eq!(scene.input_plane_count, 2);
+        assert_eq!(scene.inputs().planes[1], Plane::UP);
+    }
+
+    #[test]
+    fn recompute_two_planes_meet_in_a_line() {
+        let mut scene = PGAScene::new()
+            .with_name(PGAScene::TWO_PLANES_MEET_IN_A_LINE)
+            .with_plane(Plane::LEFT, None)
+            .with_plane(Plane::UP, None)
+            .with_expressions(vec![
+                Expr::Plane(0),
+                Expr::Plane(1),
+                Expr::Meet(0, 1),
+                Expr::Show(2),
+            ]);
+        scene.recompute();
+        assert_eq!(scene.lines[0], -Line::Z_AXIS);
 
-                // Output
+        scene.planes[1] = Plane::FORWARD;
+        scene.recompute();
+        assert_eq!(scene.lines[0], Line::Y_AXIS);
+    }
+
+    #[test]
+    fn scene_round_trips_through_json() {
+        let scene = library_scene(PGAScene::THREE_PLANES_MEET_IN_A_POINT);
+        let json = serde_json::to_string(&scene).unwrap();
+        let loaded: PGAScene = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded, scene);
+    }
+
+    #[test]
+    fn scene_files_without_the_newer_fields_still_load() {
+        let scene = library_scene(PGAScene::THREE_PLANES_MEET_IN_A_POINT);
+        let mut json = serde_json::to_value(&scene).unwrap();
+        let fields = json.as_object_mut().unwrap();
+        for field in [
+            "scalars",
+            "input_scalar_count",
+            "scalar_ranges",
+            "animation",
+            "angular_velocity",
+            "point_colors",
+            "line_colors",
+            "plane_colors",
+            "direction_colors",
+        ] {
+            fields.remove(field).unwrap();
+        }
+        let loaded: PGAScene = serde_json::from_value(json).unwrap();
+        assert_eq!(loaded.planes, scene.planes);
+        assert_eq!(loaded.expressions, scene.expressions);
+        assert!(loaded.scalars.is_empty());
+        assert_eq!(loaded.animation, None);
+    }
 
-                let line = p0 ^ p1;
-                scene.lines[0] = line;
-                let orthogonal_plane = line ^ !plane0.direction();
-                scene.planes[1] = orthogonal_plane;
+    #[test]
+    fn loading_rejects_inconsistent_scenes() {
+        let path = std::env::temp_dir().join("pga_inconsistent_scene.json");
+        let path = path.to_str().unwrap();
+        let scene = library_scene(PGAScene::THREE_PLANES_MEET_IN_A_POINT);
+        scene.save(path).unwrap();
+        assert_eq!(PGAScene::load(path), Ok(scene.clone()));
 
-                scene.lines[1] = plane0 & orthogonal_plane;
+        let too_many_inputs = PGAScene {
+            input_plane_count: scene.planes.len() + 1,
+            ..scene.clone()
+        };
+        let too_many_colors = PGAScene {
+            line_colors: vec![SceneColor::RED; scene.lines.len() + 1],
+            ..scene.clone()
+        };
+        let reversed_range = scene.clone().with_scalar(0.0, Some((1.0, -1.0)));
+        for invalid in [too_many_inputs, too_many_colors, reversed_range] {
+            invalid.save(path).unwrap();
+            assert!(PGAScene::load(path).is_err());
+        }
+        std::fs::remove_file(path).unwrap();
+    }
+
+    fn library_scene(name: &str) -> PGAScene {
+        PGAScene::library()
+            .into_iter()
+            .find(|scene| scene.name == name)
+            .unwrap()
+    }
+
+    #[test]
+    fn library_expressions_evaluate_and_keep_the_entity_counts() {
+        for scene in PGAScene::library() {
+            let inputs = Inputs {
+                points: &scene.points[..scene.input_point_count],
+                directions: &scene.directions[..scene.input_direction_count],
+                planes: &scene.planes[..scene.input_plane_count],
+                scalars: &scene.scalars[..scene.input_scalar_count],
+            };
+            if let Err(message) = evaluate(&scene.expressions, inputs) {
+                panic!("{}: {message}", scene.name);
             }
-            _ => { /* Empty scene or unrecognized scene name */ }
+            let mut recomputed = scene.clone();
+            recomputed.recompute();
+            assert_eq!(
+                recomputed.points.len(),
+                scene.points.len(),
+                "{}",
+                scene.name
+            );
+            assert_eq!(recomputed.lines.len(), scene.lines.len(), "{}", scene.name);
+            assert_eq!(
+                recomputed.planes.len(),
+                scene.planes.len(),
+                "{}",
+                scene.name
+            );
         }
     }
+
+    #[test]
+    fn expressions_match_the_hand_written_constructions() {
+        let mut scene = library_scene(PGAScene::LINE_AND_PLANE_MEET_IN_A_POINT);
+        scene.recompute();
+        let line = scene.points[0] ^ scene.points[1];
+        assert_eq!(scene.lines[0], line);
+        assert_eq!(scene.points[2], Point3::from(line & scene.planes[0]));
+
+        let mut scene = library_scene(PGAScene::PROJECT_POINT_ONTO_PLANE);
+        scene.recompute();
+        let plane = scene.planes[0];
+        let perpendicular = scene.points[0] ^ !plane.direction();
+        assert_eq!(scene.points[1], Point3::from(plane & perpendicular));
+
+        let mut scene = library_scene(PGAScene::PROJECT_LINE_ONTO_PLANE);
+        scene.recompute();
+        let plane = scene.planes[0];
+        let line = scene.points[0] ^ scene.points[1];
+        let orthogonal_plane = line ^ !plane.direction();
+        assert_eq!(scene.lines[0], line);
+        assert_eq!(scene.planes[1], orthogonal_plane);
+        assert_eq!(scene.lines[1], plane & orthogonal_plane);
+
+        let mut scene = library_scene(PGAScene::THREE_PLANES_MEET_IN_A_POINT);
+        scene.recompute();
+        let [p0, p1, p2] = [scene.planes[0], scene.planes[1], scene.planes[2]];
+        assert_eq!(scene.points[0], Point3::from(p0 & p1 & p2));
+    }
+
+    /// The foot of the perpendicular from `point` to the plane `n · x + w = 0`, by vector algebra.
+    fn foot_on_plane(point: Vec3, plane: Plane) -> Vec3 {
+        let normal = Vec3::from(plane.direction());
+        point - normal * (normal.dot(point) + plane.w) / normal.length_squared()
+    }
+
+    /// The line through `point` along `direction`, from its Plücker coordinates.
+    fn line_through(point: Vec3, direction: Vec3) -> Line {
+        Line::from_plucker(direction.into(), point.cross(direction).into())
+    }
+
+    /// The plane through `point` with the given normal.
+    fn plane_through(point: Vec3, normal: Vec3) -> Plane {
+        Plane::new(normal.x, normal.y, normal.z, -normal.dot(point))
+    }
+
+    fn assert_same_entity<T: GeometricEntity + std::fmt::Debug>(actual: T, expected: T) {
+        assert!(
+            actual.projective_approx_eq_unsigned(&expected, 1e-5),
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    #[test]
+    fn projection_scenes_match_the_analytic_answers() {
+        let mut scene = library_scene(PGAScene::PROJECT_POINT_ONTO_PLANE);
+        scene.recompute();
+        let foot = foot_on_plane(Vec3::from(scene.points[0]), scene.planes[0]);
+        assert!(Vec3::from(scene.points[1]).abs_diff_eq(foot, 1e-5));
+
+        let mut scene = library_scene(PGAScene::PROJECT_LINE_ONTO_PLANE);
+        scene.recompute();
+        let plane = scene.planes[0];
+        let a = foot_on_plane(Vec3::from(scene.points[0]), plane);
+        let b = foot_on_plane(Vec3::from(scene.points[1]), plane);
+        assert_same_entity(scene.lines[1], line_through(a, b - a));
+
+        let mut scene = library_scene(PGAScene::PROJECT_PLANE_ONTO_POINT);
+        scene.recompute();
+        let point = Vec3::from(scene.points[0]);
+        let normal = Vec3::from(scene.planes[0].direction());
+        assert_same_entity(scene.lines[0], line_through(point, normal));
+        assert_same_entity(scene.planes[1], plane_through(point, normal));
+    }
+
+    #[test]
+    fn reflection_and_rotation_scenes_match_the_analytic_answers() {
+        let mut scene = library_scene(PGAScene::REFLECT_POINT_ACROSS_PLANE);
+        scene.recompute();
+        let point = Vec3::from(scene.points[0]);
+        let mirrored = 2.0 * foot_on_plane(point, scene.planes[0]) - point;
+        assert!(Vec3::from(scene.points[1]).abs_diff_eq(mirrored, 1e-5));
+
+        // A quarter turn about +Y takes the points on the line from (x, y, z) to (z, y, -x)
+        let mut scene = library_scene(PGAScene::ROTATE_LINE_ABOUT_AXIS);
+        scene.recompute();
+        let turn = |p: Point3| Vec3::new(p.z, p.y, -p.x);
+        let (a, b) = (turn(scene.points[0]), turn(scene.points[1]));
+        assert_same_entity(scene.lines[2], line_through(a, b - a));
+    }
+
+    #[test]
+    fn perpendicular_scenes_match_the_analytic_answers() {
+        let mut scene = library_scene(PGAScene::LINE_PERP_THROUGH_POINT);
+        scene.recompute();
+        let normal = Vec3::from(scene.planes[0].direction());
+        let point = Vec3::from(scene.points[0]);
+        assert_same_entity(scene.lines[0], line_through(point, normal));
+
+        let mut scene = library_scene(PGAScene::PLANE_PERP_THROUGH_POINT);
+        scene.recompute();
+        let along = Vec3::from(scene.points[2]) - Vec3::from(scene.points[1]);
+        let point = Vec3::from(scene.points[0]);
+        assert_same_entity(scene.planes[0], plane_through(point, along));
+
+        // The plane holds both points and the normal of p0
+        let mut scene = library_scene(PGAScene::PLANE_PERP_THROUGH_LINE);
+        scene.recompute();
+        let (a, b) = (Vec3::from(scene.points[0]), Vec3::from(scene.points[1]));
+        let normal = (b - a).cross(Vec3::from(scene.planes[0].direction()));
+        assert_same_entity(scene.planes[1], plane_through(a, normal));
+    }
+
+    #[test]
+    fn moving_a_scalar_recomputes_the_scene() {
+        let mut scene = PGAScene::new()
+            .with_point(Point3::new(1.0, 0.0, 0.0), None)
+            .with_point(Point3::new(0.0, 0.0, 0.0), None)
+            .with_direction(Direction::new(0.0, 0.0, 1.0), None)
+            .with_scalar(0.0, None)
+            .with_expressions(vec![
+                Expr::Point(0),
+                Expr::Point(1),
+                Expr::Direction(0),
+                Expr::Join(1, 2),
+                Expr::Scalar(0),
+                Expr::Rotate(3, 0, 4),
+                Expr::Show(5),
+            ]);
+        assert_eq!(scene.input_scalar_count, 1);
+        assert_eq!(scene.scalar_range(0), None);
+        scene.recompute();
+        assert!(Vec3::from(scene.points[2]).abs_diff_eq(Vec3::X, 1e-6));
+
+        scene.scalars[0] = std::f32::consts::FRAC_PI_2;
+        assert!(scene.needs_recompute(&[Input::Scalar(0)]));
+        assert!(!scene.needs_recompute(&[Input::Scalar(1)]));
+        scene.recompute();
+        assert!(Vec3::from(scene.points[2]).abs_diff_eq(Vec3::Y, 1e-6));
+
+        // Scalars are saved with the other inputs
+        let mut inputs = scene.inputs();
+        assert_eq!(inputs.scalars, vec![std::f32::consts::FRAC_PI_2]);
+        inputs.scalars[0] = 1.0;
+        scene.set_inputs(inputs).unwrap();
+        assert_eq!(scene.scalars, vec![1.0]);
+    }
+
+    #[test]
+    fn bounds_cover_the_positions_of_finite_entities() {
+        assert_eq!(PGAScene::new().bounds(), None);
+        let directions = PGAScene::new().with_direction(Direction::new(1.0, 0.0, 0.0), None);
+        assert_eq!(directions.bounds(), None);
+
+        let scene = PGAScene::new()
+            .with_point(Point3::new(1.0, -2.0, 0.0), None)
+            .with_line(Line::X_AXIS, None)
+            .with_plane(Plane::new(0.0, 0.0, 1.0, -3.0), None)
+            .with_plane(Plane::new(0.0, 0.0, 0.0, 1.0), None);
+        let (min, max) = scene.bounds().unwrap();
+        assert!(min.abs_diff_eq(Vec3::new(0.0, -2.0, 0.0), 1e-6));
+        assert!(max.abs_diff_eq(Vec3::new(1.0, 0.0, 3.0), 1e-6));
+    }
+
+    #[test]
+    fn invalid_expressions_leave_the_scene_unchanged() {
+        let mut scene = PGAScene::new()
+            .with_point(Point3::new(1.0, 0.0, 0.0), None)
+            .with_expressions(vec![Expr::Point(0), Expr::Meet(0, 0), Expr::Show(1)]);
+        scene.recompute();
+        assert_eq!(scene.points.len(), 1);
+        assert!(scene.lines.is_empty());
+    }
+
+    #[test]
+    fn editing_an_input_only_dirties_what_reads_it() {
+        let scene = PGAScene::new()
+            .with_point(Point3::new(1.0, 0.0, 0.0), None)
+            .with_point(Point3::new(0.0, 1.0, 0.0), None)
+            .with_point(Point3::new(0.0, 0.0, 1.0), None)
+            .with_expressions(vec![
+                Expr::Point(0),
+                Expr::Point(1),
+                Expr::Join(0, 1),
+                Expr::Show(2),
+            ]);
+        assert_eq!(
+            dirty_steps(&scene.expressions, &[Input::Point(0)]),
+            vec![true, false, true, true]
+        );
+        assert!(scene.needs_recompute(&[Input::Point(0)]));
+        assert!(!scene.needs_recompute(&[Input::Point(2)]));
+        assert!(!scene.needs_recompute(&[Input::Plane(0)]));
+    }
 }
@@ -1,6 +1,25 @@
-use crate::visualization::PGAVisualizationApp;
+use crate::pgai::{Plane, Point3};
+use crate::visualization::{PGAScene, PGAVisualizationApp, SceneChangedEvent, SceneSelector};
+use bevy::prelude::*;
+use std::sync::Mutex;
 use wasm_bindgen::prelude::*;
 
+/// Edits requested from JavaScript. They are queued because the app may not be running yet when
+/// the page calls in, and applied to the current scene at the next frame.
+enum WebCommand {
+    AddPoint(Point3),
+    AddPlane(Plane),
+    ClearScene,
+}
+
+static PENDING_COMMANDS: Mutex<Vec<WebCommand>> = Mutex::new(Vec::new());
+
+fn queue(command: WebCommand) {
+    if let Ok(mut pending) = PENDING_COMMANDS.lock() {
+        pending.push(command);
+    }
+}
+
 /// Initialize the PGA visualization for web
 #[wasm_bindgen(start)]
 pub fn start() {
@@ -17,3 +36,48 @@ pub fn handle_visibility_change(visible: bool) {
     // Could be used to pause/resume the app when tab is not visible
     web_sys::console::log_1(&format!("Visibility changed: {}", visible).into());
 }
+
+/// Adds an input point to the current scene
+#[wasm_bindgen]
+pub fn add_point(x: f32, y: f32, z: f32) {
+    queue(WebCommand::AddPoint(Point3::new(x, y, z)));
+}
+
+/// Adds an input plane x·X + y·Y + z·Z + w = 0 to the current scene
+#[wasm_bindgen]
+pub fn add_plane(x: f32, y: f32, z: f32, w: f32) {
+    queue(WebCommand::AddPlane(Plane::new(x, y, z, w)));
+}
+
+/// Replaces the current scene with an empty one, to be filled with `add_point` and `add_plane`.
+/// Entities added to one of the built-in scenes become extra inputs after its own, drawn and
+/// editable but not read by its expressions.
+#[wasm_bindgen]
+pub fn clear_scene() {
+    queue(WebCommand::ClearScene);
+}
+
+/// Applies the edits queued from JavaScript
+pub(crate) fn apply_web_commands(
+    mut scene_selector: ResMut<SceneSelector>,
+    mut notify_scene_changed: EventWriter<SceneChangedEvent>,
+) {
+    let commands = match PENDING_COMMANDS.lock() {
+        Ok(mut pending) => std::mem::take(&mut *pending),
+        Err(_) => return,
+    };
+    if commands.is_empty() {
+        return;
+    }
+    let scene = scene_selector.current_mut();
+    for command in commands {
+        let current = std::mem::take(scene);
+        *scene = match command {
+            WebCommand::AddPoint(point) => current.with_point(point, None),
+            WebCommand::AddPlane(plane) => current.with_plane(plane, None),
+            WebCommand::ClearScene => PGAScene::new().with_name(PGAScene::EMPTY_SCENE),
+        };
+    }
+    // The number of entities changed, which a scene change handles
+    notify_scene_changed.write(SceneChangedEvent);
+}